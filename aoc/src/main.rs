@@ -0,0 +1,414 @@
+use std::io::{self, Write};
+use std::path::Path;
+use utility::audit::{self, AuditSettings};
+use utility::batch;
+use utility::catalog::{self, Benchmarks, BENCHMARKS_PATH};
+use utility::identify;
+use utility::read_file_lines;
+use utility::runner::{self, Day, Outcome};
+use utility::scaling;
+
+const DAYS: [&Day; 25] = [
+    &day1::DAY,
+    &day2::DAY,
+    &day3::DAY,
+    &day4::DAY,
+    &day5::DAY,
+    &day6::DAY,
+    &day7::DAY,
+    &day8::DAY,
+    &day9::DAY,
+    &day10::DAY,
+    &day11::DAY,
+    &day12::DAY,
+    &day13::DAY,
+    &day14::DAY,
+    &day15::DAY,
+    &day16::DAY,
+    &day17::DAY,
+    &day18::DAY,
+    &day19::DAY,
+    &day20::DAY,
+    &day21::DAY,
+    &day22::DAY,
+    &day23::DAY,
+    &day24::DAY,
+    &day25::DAY,
+];
+
+const USAGE: &str = "Usage: aoc [run] [--json FILE] [--benchmark] [DAY...]
+       aoc audit [--runs N] [--threads N,N...] [DAY...]
+       aoc batch DAY DIR
+       aoc identify FILE
+       aoc scale [DAY...]
+       aoc list [DAY...]
+
+Runs the given days (all days if none are given). A day can be given as
+`7` or `day7`. Input files are read relative to the repository root.
+    --json <FILE>         Write answers, timings, spans and metrics to a JSON file
+    --benchmark           Record each day's total time in benchmarks.txt
+
+audit runs each part repeatedly, each time with differently seeded hashers,
+first on one thread and then on several at once. It reports any part whose
+answer or --trace output changes from run to run.
+    --runs <N>            Rounds to run at each thread count (default 2)
+    --threads <N,N...>    Thread counts to run each round on (default 1,2)
+
+batch runs a day on every file in DIR, reporting each one's answers, timings
+and failures. An input's expected answers can be put next to it in a file of
+the same name with an .answer extension: part 1's answer on the first line,
+then part 2's answer.
+
+identify reports which days' input formats FILE matches, and the near misses
+with the first line or parser error that ruled them out.
+
+scale times each day's parts on generated inputs of increasing size, fits how
+fast their running time grows, and reports any part that grows faster than
+the complexity class its day declares. Days without a declared class are
+skipped.
+
+list shows each day's title, which parts are implemented, whether its example
+answers are recorded in .answer files, its last --benchmark time and the
+techniques it uses.";
+
+// Accepts either "7" or "day7"
+fn find_day(arg: &str) -> Option<&'static Day> {
+    let name = if arg.starts_with("day") {
+        arg.to_owned()
+    } else {
+        format!("day{}", arg)
+    };
+    DAYS.iter().copied().find(|day| day.name == name)
+}
+
+fn parse_days(args: &[String]) -> Vec<&'static Day> {
+    if args.is_empty() {
+        return DAYS.to_vec();
+    }
+
+    args.iter()
+        .map(|arg| {
+            find_day(arg).unwrap_or_else(|| {
+                eprintln!("Unknown day: {}\n\n{}", arg, USAGE);
+                std::process::exit(2);
+            })
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct RunArgs {
+    json: Option<String>,
+    benchmark: bool,
+    days: Vec<String>,
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut run_args = RunArgs::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => {
+                run_args.json = Some(args.next().ok_or("Missing value for --json")?.clone())
+            }
+            "--benchmark" => run_args.benchmark = true,
+            _ => run_args.days.push(arg.clone()),
+        }
+    }
+    Ok(run_args)
+}
+
+fn run(args: &[String]) -> i32 {
+    let RunArgs {
+        json,
+        benchmark,
+        days,
+    } = parse_run_args(args).unwrap_or_else(|err| {
+        eprintln!("{}\n\n{}", err, USAGE);
+        std::process::exit(2);
+    });
+    let days = parse_days(&days);
+
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+
+    let mut reports = Vec::new();
+    for day in days {
+        writeln!(stdout, "=========== {} ===========", day.name).unwrap();
+        reports.push(runner::run_day(day, day.input, &mut stdout));
+    }
+
+    if let Some(path) = &json {
+        runner::write_json_report(path, &reports).expect("Unable to write JSON report");
+        writeln!(stdout, "Wrote report to {}", path).unwrap();
+    }
+    if benchmark {
+        let mut benchmarks = Benchmarks::read(BENCHMARKS_PATH).expect("Unable to read benchmarks");
+        benchmarks.record(&reports);
+        benchmarks
+            .write(BENCHMARKS_PATH)
+            .expect("Unable to write benchmarks");
+        writeln!(stdout, "Recorded times in {}", BENCHMARKS_PATH).unwrap();
+    }
+
+    if runner::write_failure_summary(&mut stdout, &reports) > 0 {
+        1
+    } else {
+        0
+    }
+}
+
+fn parse_audit_args(args: &[String]) -> Result<(AuditSettings, Vec<String>), String> {
+    let mut settings = AuditSettings::default();
+    let mut days = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" => {
+                let value = args.next().ok_or("Missing value for --runs")?;
+                settings.runs = value
+                    .parse()
+                    .map_err(|_| format!("Invalid value for --runs: {}", value))?;
+            }
+            "--threads" => {
+                let value = args.next().ok_or("Missing value for --threads")?;
+                settings.thread_counts = value
+                    .split(',')
+                    .map(|count| count.parse::<usize>().ok().filter(|count| *count > 0))
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or_else(|| format!("Invalid value for --threads: {}", value))?;
+            }
+            _ => days.push(arg.clone()),
+        }
+    }
+    Ok((settings, days))
+}
+
+fn audit(args: &[String]) -> i32 {
+    let (settings, days) = parse_audit_args(args).unwrap_or_else(|err| {
+        eprintln!("{}\n\n{}", err, USAGE);
+        std::process::exit(2);
+    });
+
+    let mut unstable = Vec::new();
+    for day in parse_days(&days) {
+        println!("=========== {} ===========", day.name);
+        let Ok(file_lines) = runner::catch_panic(|| read_file_lines(day.input)) else {
+            println!("Unable to read {}\n", day.input);
+            unstable.push(format!("{} input", day.name));
+            continue;
+        };
+
+        for part_name in ["part1", "part2"] {
+            let part = |file_lines: &[String]| day.solve_part(part_name, file_lines);
+            let result = audit::audit_part(part_name, &part, &file_lines, &settings);
+            let outcomes = result.distinct_outcomes();
+            let traces = result.distinct_traces();
+            let runs = result.runs.len();
+            if result.is_deterministic() {
+                println!("{}: same result over {} runs", part_name, runs);
+                continue;
+            }
+
+            unstable.push(format!("{} {}", day.name, part_name));
+            if outcomes.len() > 1 {
+                println!(
+                    "{}: {} different results over {} runs:",
+                    part_name,
+                    outcomes.len(),
+                    runs
+                );
+                for outcome in outcomes {
+                    match outcome {
+                        Outcome::Parsed => println!("    parsed"),
+                        Outcome::Answer(answer) => println!("    answer: {}", answer),
+                        Outcome::Panicked(caught) => println!("    panicked: {}", caught),
+                        Outcome::Skipped => println!("    skipped"),
+                    }
+                }
+            }
+            if traces > 1 {
+                println!(
+                    "{}: {} different traces over {} runs",
+                    part_name, traces, runs
+                );
+            }
+        }
+        println!();
+    }
+
+    if unstable.is_empty() {
+        println!("All parts gave the same results on every run");
+        0
+    } else {
+        println!(
+            "{} not deterministic: {}",
+            unstable.len(),
+            unstable.join(", ")
+        );
+        1
+    }
+}
+
+fn batch(args: &[String]) -> i32 {
+    let [day, dir] = args else {
+        eprintln!("batch takes a day and a directory\n\n{}", USAGE);
+        return 2;
+    };
+    let day = parse_days(std::slice::from_ref(day))[0];
+
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+    let files = match batch::run_batch(day, Path::new(dir), &mut stdout) {
+        Ok(files) => files,
+        Err(err) => {
+            eprintln!("Unable to read {}: {}", dir, err);
+            return 2;
+        }
+    };
+
+    let failed = files
+        .iter()
+        .filter(|file| !file.passed())
+        .collect::<Vec<_>>();
+    writeln!(
+        stdout,
+        "{} of {} input(s) passed",
+        files.len() - failed.len(),
+        files.len()
+    )
+    .unwrap();
+    for file in failed.iter() {
+        writeln!(
+            stdout,
+            "  {}: {} failed step(s), {} wrong answer(s)",
+            file.path.display(),
+            file.report.failures().count(),
+            file.wrong_answers()
+        )
+        .unwrap();
+    }
+
+    if failed.is_empty() {
+        0
+    } else {
+        1
+    }
+}
+
+fn identify(args: &[String]) -> i32 {
+    let [file] = args else {
+        eprintln!("identify takes a file\n\n{}", USAGE);
+        return 2;
+    };
+    let file_lines = match std::fs::read_to_string(file) {
+        Ok(contents) => contents.lines().map(|s| s.to_string()).collect::<Vec<_>>(),
+        Err(err) => {
+            eprintln!("Unable to read {}: {}", file, err);
+            return 2;
+        }
+    };
+
+    let identifications = identify::identify_all(&DAYS, &file_lines);
+    let matches = identifications
+        .iter()
+        .filter(|found| found.is_match())
+        .collect::<Vec<_>>();
+    for found in matches.iter() {
+        println!("{}: matches ({} lines)", found.day, found.lines);
+    }
+    if matches.is_empty() {
+        println!("No day's input format matches {}", file);
+    }
+
+    let near_misses = identifications
+        .iter()
+        .filter(|found| found.is_near_miss())
+        .collect::<Vec<_>>();
+    if !near_misses.is_empty() {
+        println!("\nNear misses:");
+    }
+    for found in near_misses {
+        println!(
+            "{}: {:.0}% of lines match, {}",
+            found.day,
+            found.confidence() * 100.0,
+            found.first_error.as_deref().unwrap_or_default()
+        );
+    }
+
+    if matches.is_empty() {
+        1
+    } else {
+        0
+    }
+}
+
+fn list(args: &[String]) -> i32 {
+    let benchmarks = match Benchmarks::read(BENCHMARKS_PATH) {
+        Ok(benchmarks) => benchmarks,
+        Err(err) => {
+            eprintln!("Unable to read {}: {}", BENCHMARKS_PATH, err);
+            return 2;
+        }
+    };
+    catalog::write_catalog(&mut io::stdout(), &parse_days(args), &benchmarks).unwrap();
+    0
+}
+
+fn scale(args: &[String]) -> i32 {
+    let mut too_slow = Vec::new();
+    for day in parse_days(args) {
+        let Some(scaling) = &day.scaling else {
+            continue;
+        };
+        println!("=========== {} ===========", day.name);
+        for part in scaling::measure(day, scaling) {
+            scaling::write_part_scaling(&mut io::stdout(), &part).unwrap();
+            if !part.passed() {
+                too_slow.push(format!("{} {}", day.name, part.name));
+            }
+        }
+        println!();
+    }
+
+    if too_slow.is_empty() {
+        println!("All parts grew within their declared complexity");
+        0
+    } else {
+        println!(
+            "{} grew faster than declared: {}",
+            too_slow.len(),
+            too_slow.join(", ")
+        );
+        1
+    }
+}
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let (command, rest) = match args.first().map(|s| s.as_str()) {
+        Some("run") => ("run", &args[1..]),
+        Some("audit") => ("audit", &args[1..]),
+        Some("batch") => ("batch", &args[1..]),
+        Some("identify") => ("identify", &args[1..]),
+        Some("scale") => ("scale", &args[1..]),
+        Some("list") => ("list", &args[1..]),
+        Some("help" | "-h" | "--help") => ("help", &args[1..]),
+        _ => ("run", &args[..]),
+    };
+
+    let exit_code = match command {
+        "run" => run(rest),
+        "audit" => audit(rest),
+        "batch" => batch(rest),
+        "identify" => identify(rest),
+        "scale" => scale(rest),
+        "list" => list(rest),
+        _ => {
+            println!("{}", USAGE);
+            0
+        }
+    };
+    std::process::exit(exit_code);
+}
@@ -0,0 +1,56 @@
+use utility::catalog::{Info, Technique};
+use utility::identify;
+use utility::runner::{Day, Solution};
+
+pub const DAY: Day = Day {
+    name: "day1",
+    input: "day1/input.txt",
+    info: Info {
+        title: "Calorie Counting",
+        implemented: [true, true],
+        techniques: &[Technique::Sorting],
+        examples: &[],
+    },
+    solution: &Solution {
+        parse: parse_elves,
+        part1,
+        part2,
+    },
+    is_input_line,
+    visualize: None,
+    scaling: None,
+};
+
+// Calorie counts, with a blank line after each elf
+fn is_input_line(line: &str) -> bool {
+    line.is_empty() || identify::is_made_of(line, "0123456789")
+}
+
+fn parse_elves(file_lines: &[String]) -> Vec<i64> {
+    let mut elves_vec = Vec::new();
+    let mut cur_total = 0;
+    for line in file_lines {
+        let line = line.trim();
+        if line.is_empty() {
+            elves_vec.push(cur_total);
+            cur_total = 0;
+        } else {
+            // Parse the line as an integer
+            let line_int = line.parse::<i64>().unwrap();
+            cur_total += line_int;
+        }
+    }
+    elves_vec
+}
+
+fn part1(elves_vec: &[i64]) -> String {
+    let max_total = elves_vec.iter().max().unwrap();
+    max_total.to_string()
+}
+
+fn part2(elves_vec: &[i64]) -> String {
+    let mut elves_vec = elves_vec.to_vec();
+    elves_vec.sort_by(|a, b| b.cmp(a));
+    let top_three = elves_vec[0..3].iter().sum::<i64>();
+    top_three.to_string()
+}
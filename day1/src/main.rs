@@ -0,0 +1,3 @@
+fn main() {
+    utility::runner::main(&day1::DAY);
+}
@@ -0,0 +1,215 @@
+use itertools::Itertools;
+use std::collections::HashSet;
+use utility::catalog::{Info, Technique};
+use utility::identify;
+use utility::runner::{Day, Solution};
+use utility::visualizer::Simulation;
+
+pub const DAY: Day = Day {
+    name: "day10",
+    input: "day10/input.txt",
+    //input: "day10/example-input.txt",
+    info: Info {
+        title: "Cathode-Ray Tube",
+        implemented: [true, true],
+        techniques: &[Technique::Simulation],
+        examples: &["day10/example-input.txt"],
+    },
+    solution: &Solution {
+        parse: parse_commands,
+        part1,
+        part2,
+    },
+    is_input_line,
+    visualize: Some(visualize),
+    scaling: None,
+};
+
+fn is_input_line(line: &str) -> bool {
+    line == "noop" || line.strip_prefix("addx ").is_some_and(identify::is_int)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Command {
+    Noop,
+    AddX(isize),
+}
+
+impl Command {
+    fn get_cycles(&self) -> usize {
+        match self {
+            Command::Noop => 1,
+            Command::AddX(_) => 2,
+        }
+    }
+
+    fn execute(&self, x: isize) -> isize {
+        match self {
+            Command::Noop => x,
+            Command::AddX(x2) => x + x2,
+        }
+    }
+}
+
+fn parse_commands(file_lines: &[String]) -> Vec<Command> {
+    let mut commands = Vec::new();
+    for line in file_lines {
+        if line == "noop" {
+            commands.push(Command::Noop);
+            continue;
+        }
+
+        let (cmd, val) = line.split(' ').collect_tuple().unwrap();
+        let val: isize = val.parse().unwrap();
+        match cmd {
+            "addx" => commands.push(Command::AddX(val)),
+            _ => panic!("Unknown command: {}", cmd),
+        }
+    }
+    commands
+}
+
+struct Executor {
+    commands: Vec<Command>,
+    command_index: usize,
+    cycle_count: isize,
+    cycles_until_next_command: usize,
+    x: isize,
+    pendingx: isize,
+    have_pendingx: bool,
+}
+
+impl Executor {
+    fn new(commands: Vec<Command>) -> Executor {
+        Executor {
+            commands,
+            command_index: 0,
+            cycle_count: 0,
+            cycles_until_next_command: 0,
+            x: 1,
+            pendingx: 0,
+            have_pendingx: false,
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        if self.have_pendingx {
+            self.x = self.pendingx;
+            self.have_pendingx = false;
+        }
+
+        if self.command_index >= self.commands.len() {
+            return false;
+        }
+
+        self.cycle_count += 1;
+        if self.cycles_until_next_command == 0 {
+            self.cycles_until_next_command = self.commands[self.command_index].get_cycles() - 1;
+        } else {
+            self.cycles_until_next_command -= 1;
+        }
+
+        if self.cycles_until_next_command == 0 {
+            self.pendingx = self.commands[self.command_index].execute(self.x);
+            self.have_pendingx = true;
+            self.command_index += 1;
+        }
+        true
+    }
+
+    fn strength(&self) -> isize {
+        self.x * self.cycle_count
+    }
+}
+
+fn part1(commands: &[Command]) -> String {
+    let mut executor = Executor::new(commands.to_vec());
+    let important_cycles: HashSet<isize> = [20, 60, 100, 140, 180, 220].iter().copied().collect();
+    let mut important_cycles_sum = 0;
+    loop {
+        if !executor.step() {
+            break;
+        }
+
+        if important_cycles.contains(&executor.cycle_count) {
+            important_cycles_sum += executor.strength();
+        }
+    }
+
+    important_cycles_sum.to_string()
+}
+
+const CRT_WIDTH: usize = 40;
+const CRT_HEIGHT: usize = 6;
+
+// Draws a pixel for each cycle the executor runs
+struct Crt {
+    executor: Executor,
+    pixels: Vec<char>,
+    pos: usize,
+}
+
+impl Crt {
+    fn new(commands: Vec<Command>) -> Crt {
+        Crt {
+            executor: Executor::new(commands),
+            pixels: vec![' '; CRT_WIDTH * CRT_HEIGHT],
+            pos: 0,
+        }
+    }
+
+    fn render_screen(&self) -> String {
+        let mut output = String::new();
+        for row in 0..CRT_HEIGHT {
+            for col in 0..CRT_WIDTH {
+                output.push(self.pixels[row * CRT_WIDTH + col]);
+            }
+            output.push('\n');
+        }
+        output
+    }
+}
+
+impl Simulation for Crt {
+    fn step(&mut self) -> bool {
+        if !self.executor.step() {
+            return false;
+        }
+
+        let x = self.executor.x;
+        let crt_col: isize = (self.pos % CRT_WIDTH) as isize;
+        self.pixels[self.pos] = if (x - crt_col).abs() <= 1 { 'X' } else { '.' };
+        self.pos += 1;
+        true
+    }
+
+    fn render(&self) -> String {
+        let sprite = (0..CRT_WIDTH as isize)
+            .map(|col| {
+                if (self.executor.x - col).abs() <= 1 {
+                    '#'
+                } else {
+                    ' '
+                }
+            })
+            .collect::<String>();
+        format!(
+            "Cycle: {} X: {}\n{}\n\n{}",
+            self.executor.cycle_count,
+            self.executor.x,
+            sprite,
+            self.render_screen()
+        )
+    }
+}
+
+fn visualize(file_lines: &[String]) -> Box<dyn Simulation> {
+    Box::new(Crt::new(parse_commands(file_lines)))
+}
+
+fn part2(commands: &[Command]) -> String {
+    let mut crt = Crt::new(commands.to_vec());
+    while crt.step() {}
+
+    "\n".to_owned() + &crt.render_screen()
+}
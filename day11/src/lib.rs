@@ -0,0 +1,205 @@
+use utility::catalog::{Info, Technique};
+use utility::runner::{Day, Solution};
+use utility::scaling::{Complexity, Random, Scaling};
+
+pub const DAY: Day = Day {
+    name: "day11",
+    input: "day11/input.txt",
+    //input: "day11/example-input.txt",
+    info: Info {
+        title: "Monkey in the Middle",
+        implemented: [true, true],
+        techniques: &[Technique::Simulation, Technique::ModularArithmetic],
+        examples: &["day11/example-input.txt"],
+    },
+    solution: &Solution {
+        parse: parse_monkeys,
+        part1,
+        part2,
+    },
+    is_input_line,
+    visualize: None,
+    scaling: Some(Scaling {
+        generate,
+        sizes: &[100, 200, 400],
+        part1: Some(Complexity::Quadratic),
+        part2: Some(Complexity::Quadratic),
+    }),
+};
+
+// Each monkey is a block of lines like these, followed by a blank line
+fn is_input_line(line: &str) -> bool {
+    const PREFIXES: [&str; 6] = [
+        "Monkey ",
+        "Starting items:",
+        "Operation: new = old ",
+        "Test: divisible by ",
+        "If true: throw to monkey ",
+        "If false: throw to monkey ",
+    ];
+    let line = line.trim();
+    line.is_empty() || PREFIXES.iter().any(|prefix| line.starts_with(prefix))
+}
+
+// Monkeys take items off the front of a Vec, so each throw can shift every
+// item they're holding. Splits n items between four monkeys like the example's.
+fn generate(n: usize) -> Vec<String> {
+    const MONKEYS: [(&str, usize, usize, usize); 4] = [
+        ("* 19", 23, 2, 3),
+        ("+ 6", 19, 2, 0),
+        ("* old", 13, 1, 3),
+        ("+ 3", 17, 0, 1),
+    ];
+    let mut random = Random::new(11);
+    let mut lines = Vec::new();
+    for (index, (operation, test_mod, true_monkey, false_monkey)) in MONKEYS.iter().enumerate() {
+        let items = (0..n / MONKEYS.len())
+            .map(|_| random.range(50, 99).to_string())
+            .collect::<Vec<_>>();
+        lines.extend([
+            format!("Monkey {}:", index),
+            format!("  Starting items: {}", items.join(", ")),
+            format!("  Operation: new = old {}", operation),
+            format!("  Test: divisible by {}", test_mod),
+            format!("    If true: throw to monkey {}", true_monkey),
+            format!("    If false: throw to monkey {}", false_monkey),
+            String::new(),
+        ]);
+    }
+    lines
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operation {
+    Add(usize),
+    Multiply(usize),
+    MultiplySelf,
+}
+
+impl Operation {
+    fn execute(&self, x: usize) -> usize {
+        match self {
+            Operation::Add(x2) => x + x2,
+            Operation::Multiply(x2) => x * x2,
+            Operation::MultiplySelf => x * x,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Monkey {
+    item_list: Vec<usize>,
+    operation: Operation,
+    test_mod: usize,
+    true_monkey: usize,
+    false_monkey: usize,
+    num_inspections: usize,
+}
+
+impl Monkey {
+    fn from_lines(file_lines: &[String], current_index: &mut usize) -> Monkey {
+        let starting_items_line = &file_lines[*current_index + 1].trim()[16..];
+        let operation_line = &file_lines[*current_index + 2].trim()[21..];
+        let test_line = &file_lines[*current_index + 3].trim()[19..];
+        let true_line = &file_lines[*current_index + 4].trim()[25..];
+        let false_line = &file_lines[*current_index + 5].trim()[26..];
+        *current_index += 7;
+
+        let item_list: Vec<usize> = starting_items_line
+            .split(',')
+            .map(|x| x.trim().parse().unwrap())
+            .collect();
+
+        let operation = if operation_line == "* old" {
+            Operation::MultiplySelf
+        } else if operation_line.starts_with('+') {
+            Operation::Add(operation_line[2..].parse().unwrap())
+        } else {
+            Operation::Multiply(operation_line[2..].parse().unwrap())
+        };
+
+        let test_mod = test_line.parse().unwrap();
+        let true_monkey = true_line.parse().unwrap();
+        let false_monkey = false_line.parse().unwrap();
+
+        Monkey {
+            item_list,
+            operation,
+            test_mod,
+            true_monkey,
+            false_monkey,
+            num_inspections: 0,
+        }
+    }
+}
+
+fn parse_monkeys(file_lines: &[String]) -> Vec<Monkey> {
+    let mut current_index = 0;
+    let mut monkeys: Vec<Monkey> = Vec::new();
+    while current_index < file_lines.len() {
+        monkeys.push(Monkey::from_lines(file_lines, &mut current_index));
+    }
+    monkeys
+}
+
+fn part1(monkeys: &[Monkey]) -> String {
+    let mut monkeys = monkeys.to_vec();
+
+    const NUM_ROUNDS: usize = 20;
+    for _ in 0..NUM_ROUNDS {
+        for monkey_index in 0..monkeys.len() {
+            while !monkeys[monkey_index].item_list.is_empty() {
+                monkeys[monkey_index].num_inspections += 1;
+
+                let item = monkeys[monkey_index].item_list.remove(0);
+                let item = monkeys[monkey_index].operation.execute(item) / 3;
+                let next_monkey = if item.is_multiple_of(monkeys[monkey_index].test_mod) {
+                    monkeys[monkey_index].true_monkey
+                } else {
+                    monkeys[monkey_index].false_monkey
+                };
+                monkeys[next_monkey].item_list.push(item);
+            }
+        }
+    }
+
+    let mut inspected_counts: Vec<usize> = monkeys.iter().map(|x| x.num_inspections).collect();
+    // Sort highest to lowest
+    inspected_counts.sort_by(|a, b| b.cmp(a));
+
+    // Return the product of the top two numbers
+    (inspected_counts[0] * inspected_counts[1]).to_string()
+}
+
+fn part2(monkeys: &[Monkey]) -> String {
+    let mut monkeys = monkeys.to_vec();
+
+    // Working off a modulus of the product of all the test_mods allows
+    // for the operations to be congruent to the mod of all the monkey test_mods
+    let mod_product = monkeys.iter().map(|x| x.test_mod).product::<usize>();
+
+    const NUM_ROUNDS: usize = 10000;
+    for _ in 0..NUM_ROUNDS {
+        for monkey_index in 0..monkeys.len() {
+            while !monkeys[monkey_index].item_list.is_empty() {
+                monkeys[monkey_index].num_inspections += 1;
+
+                let mut item = monkeys[monkey_index].item_list.remove(0);
+                item = monkeys[monkey_index].operation.execute(item) % mod_product;
+                let next_monkey = if item.is_multiple_of(monkeys[monkey_index].test_mod) {
+                    monkeys[monkey_index].true_monkey
+                } else {
+                    monkeys[monkey_index].false_monkey
+                };
+                monkeys[next_monkey].item_list.push(item);
+            }
+        }
+    }
+
+    let mut inspected_counts: Vec<usize> = monkeys.iter().map(|x| x.num_inspections).collect();
+    // Sort highest to lowest
+    inspected_counts.sort_by(|a, b| b.cmp(a));
+
+    // Return the product of the top two numbers
+    (inspected_counts[0] * inspected_counts[1]).to_string()
+}
@@ -0,0 +1,172 @@
+use std::collections::HashSet;
+use utility::catalog::{Info, Technique};
+use utility::dot;
+use utility::graph::GridGraph;
+use utility::grid::Grid;
+use utility::identify;
+use utility::image::{self, Gradient, Image, Rgb};
+use utility::metrics;
+use utility::runner::{Day, Solution};
+use utility::*;
+
+pub const DAY: Day = Day {
+    name: "day12",
+    input: "day12/input.txt",
+    //input: "day12/example-input.txt",
+    info: Info {
+        title: "Hill Climbing Algorithm",
+        implemented: [true, true],
+        techniques: &[Technique::AStar, Technique::Dijkstra],
+        examples: &["day12/example-input.txt"],
+    },
+    solution: &Solution {
+        parse: ParsedInput::from_lines,
+        part1,
+        part2,
+    },
+    is_input_line,
+    visualize: None,
+    scaling: None,
+};
+
+// Heights from a to z, with S and E marking the start and end
+fn is_input_line(line: &str) -> bool {
+    identify::is_made_of(line, "abcdefghijklmnopqrstuvwxyzSE")
+}
+
+type Pos = (usize, usize);
+
+struct ParsedInput {
+    heights: Grid<usize>,
+    start: Pos,
+    end: Pos,
+    valid_starts: Vec<Pos>,
+}
+
+impl ParsedInput {
+    fn from_lines(file_lines: &[String]) -> ParsedInput {
+        // Create easy lookup for heights
+        let map_heights = Grid::parse(file_lines, |c| match c {
+            'S' => Some(0),
+            'E' => Some('z' as usize - 'a' as usize),
+            'a'..='z' => Some(c as usize - 'a' as usize),
+            _ => None,
+        })
+        .expect("Unable to parse the map");
+
+        // Find the start and end locations
+        for marker in ['S', 'E'] {
+            assert!(
+                file_lines.iter().any(|line| line.contains(marker)),
+                "No {} in the map",
+                marker
+            );
+        }
+        let mut start = (0, 0);
+        let mut end = (0, 0);
+        let mut valid_starts = Vec::new();
+        for (y, line) in file_lines.iter().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if c == 'S' {
+                    start = (x, y);
+                    valid_starts.push(start);
+                } else if c == 'E' {
+                    end = (x, y);
+                } else if c == 'a' {
+                    valid_starts.push((x, y));
+                }
+            }
+        }
+
+        ParsedInput {
+            heights: map_heights,
+            start,
+            end,
+            valid_starts,
+        }
+    }
+
+    // Each step can climb at most one higher, but can drop any distance
+    fn climbing(&self) -> impl Graph<Node = Pos> + '_ {
+        GridGraph::new(&self.heights, |&from, &to| (to <= from + 1).then_some(1))
+    }
+
+    // Lays the nodes out on the grid for neato, marking the path if there is one
+    fn graph(&self, path: &[Pos]) -> dot::Graph {
+        let on_path = path.iter().copied().collect::<HashSet<_>>();
+        let path_edges = path
+            .windows(2)
+            .map(|w| (w[0], w[1]))
+            .collect::<HashSet<_>>();
+
+        let mut graph = dot::Graph::directed("day12");
+        graph
+            .attribute("layout", "neato")
+            .node_defaults(&[("shape", "square"), ("fixedsize", "true"), ("width", "0.3")])
+            .edge_defaults(&[("arrowsize", "0.3")]);
+        let climbing = self.climbing();
+        let id = |(col, row): Pos| self.heights.index_of(col, row).to_string();
+        for node in self.heights.positions() {
+            let (col, row) = node;
+            let label = if node == self.start {
+                'S'
+            } else if node == self.end {
+                'E'
+            } else {
+                (b'a' + self.heights[(col, row)] as u8) as char
+            };
+            let label = label.to_string();
+            let pos = format!("{},{}!", col as f64 / 2.0, -(row as isize) as f64 / 2.0);
+            let mut attributes = vec![("label", label.as_str()), ("pos", pos.as_str())];
+            if on_path.contains(&node) {
+                attributes.extend([("style", "filled"), ("fillcolor", "red")]);
+            }
+            graph.node(&id(node), &attributes);
+
+            for (to, _) in climbing.successors(&node) {
+                let attributes: &[(&str, &str)] = if path_edges.contains(&(node, to)) {
+                    &[("color", "red"), ("penwidth", "3")]
+                } else {
+                    &[]
+                };
+                graph.edge(&id(node), &id(to), attributes);
+            }
+        }
+        graph
+    }
+}
+
+fn part1(input: &ParsedInput) -> String {
+    // Every step moves one square, so the Manhattan distance to E never
+    // overestimates the steps left
+    let (end_x, end_y) = input.end;
+    let found = astar(
+        &input.climbing(),
+        input.start,
+        |&pos| pos == input.end,
+        |&(x, y)| (x.abs_diff(end_x) + y.abs_diff(end_y)) as i64,
+    )
+    .expect("No path to E");
+    metrics::add("nodes_expanded", found.expanded as u64);
+    let (dist, path) = (found.cost, found.path);
+
+    image::snapshot("heights", || {
+        let max_height = 'z' as usize - 'a' as usize;
+        let mut image =
+            Image::from_grid(&input.heights, &Gradient::terrain(0.0, max_height as f64));
+        for &(x, y) in path.iter() {
+            image.set(x, y, Rgb::RED);
+        }
+        image
+    });
+    dot::export(|highlight| input.graph(if highlight { &path } else { &[] }));
+
+    dist.to_string()
+}
+
+fn part2(input: &ParsedInput) -> String {
+    let paths = dijkstra_multi(&input.climbing(), &input.valid_starts);
+    let dist = paths.cost(&input.end).expect("No path to E");
+
+    dist.to_string()
+}
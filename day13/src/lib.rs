@@ -0,0 +1,165 @@
+use std::cmp::Ordering;
+use utility::catalog::{Info, Technique};
+use utility::identify;
+use utility::runner::{Day, Solution};
+
+pub const DAY: Day = Day {
+    name: "day13",
+    input: "day13/input.txt",
+    //input: "day13/example-input.txt",
+    info: Info {
+        title: "Distress Signal",
+        implemented: [true, true],
+        techniques: &[Technique::RecursiveParsing, Technique::Sorting],
+        examples: &["day13/example-input.txt"],
+    },
+    solution: &Solution {
+        parse: parse_packet_pairs,
+        part1,
+        part2,
+    },
+    is_input_line,
+    visualize: None,
+    scaling: None,
+};
+
+// Pairs of packets, with a blank line after each pair
+fn is_input_line(line: &str) -> bool {
+    line.is_empty() || (line.starts_with('[') && identify::is_made_of(line, "[],0123456789"))
+}
+
+#[derive(Debug, Clone)]
+enum PacketData {
+    Empty,
+    Value(i64),
+    List(Vec<PacketData>),
+}
+
+impl PacketData {
+    fn from_str(line: &str) -> (usize, PacketData) {
+        if line.is_empty() || line.as_bytes()[0] == b']' {
+            return (0, PacketData::Empty);
+        }
+        if line.as_bytes()[0].is_ascii_digit() {
+            let mut i = 0;
+            while line.as_bytes()[i].is_ascii_digit() {
+                i += 1;
+            }
+            let value = line[0..i].parse::<i64>().unwrap();
+            return (i, PacketData::Value(value));
+        }
+
+        assert!(line.starts_with('['));
+
+        let mut list: Vec<PacketData> = Vec::new();
+        let mut i = 1;
+        while i < line.len() {
+            let (j, data) = PacketData::from_str(&line[i..]);
+            i += j;
+            list.push(data);
+            if line.as_bytes()[i] == b']' {
+                i += 1;
+                break;
+            }
+            assert!(line.as_bytes()[i] == b',');
+            i += 1;
+        }
+
+        (i, PacketData::List(list))
+    }
+}
+
+impl PartialEq for PacketData {
+    fn eq(&self, other: &PacketData) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for PacketData {}
+
+impl PartialOrd for PacketData {
+    fn partial_cmp(&self, other: &PacketData) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PacketData {
+    fn cmp(&self, other: &PacketData) -> Ordering {
+        match (self, other) {
+            (PacketData::Empty, PacketData::Empty) => Ordering::Equal,
+            (PacketData::Empty, _) => Ordering::Less,
+            (_, PacketData::Empty) => Ordering::Greater,
+            (PacketData::Value(a), PacketData::Value(b)) => a.cmp(b),
+            (PacketData::Value(a), PacketData::List(_)) => {
+                PacketData::List(vec![PacketData::Value(*a)]).cmp(other)
+            }
+            (PacketData::List(_), PacketData::Value(b)) => {
+                self.cmp(&PacketData::List(vec![PacketData::Value(*b)]))
+            }
+            (PacketData::List(a), PacketData::List(b)) => {
+                for (a, b) in a.iter().zip(b.iter()) {
+                    match a.cmp(b) {
+                        Ordering::Less => return Ordering::Less,
+                        Ordering::Greater => return Ordering::Greater,
+                        Ordering::Equal => (),
+                    }
+                }
+                a.len().cmp(&b.len())
+            }
+        }
+    }
+}
+
+fn parse_packet_pairs(file_lines: &[String]) -> Vec<(PacketData, PacketData)> {
+    let mut packet_pairs: Vec<(PacketData, PacketData)> = Vec::new();
+    let mut cur_pair: (PacketData, PacketData) = (PacketData::Empty, PacketData::Empty);
+    for (i, line) in file_lines.iter().enumerate() {
+        if i % 3 == 2 {
+            assert!(line.is_empty());
+            continue;
+        }
+        let (j, data) = PacketData::from_str(line);
+        assert_eq!(j, line.len());
+        if i % 3 == 0 {
+            cur_pair.0 = data;
+        } else {
+            cur_pair.1 = data;
+            packet_pairs.push(cur_pair);
+            cur_pair = (PacketData::Empty, PacketData::Empty);
+        }
+    }
+    packet_pairs
+}
+
+fn part1(packet_pairs: &[(PacketData, PacketData)]) -> String {
+    let mut correct_pair_sum = 0;
+    for (pair_index, pair) in packet_pairs.iter().enumerate() {
+        if pair.0 < pair.1 {
+            correct_pair_sum += pair_index + 1;
+        }
+    }
+
+    correct_pair_sum.to_string()
+}
+
+fn part2(packet_pairs: &[(PacketData, PacketData)]) -> String {
+    let (_, token_one) = PacketData::from_str("[[2]]");
+    let (_, token_two) = PacketData::from_str("[[6]]");
+
+    let mut packet_list = Vec::new();
+    packet_list.push(token_one.clone());
+    packet_list.push(token_two.clone());
+    assert!(packet_list[0] == token_one);
+    assert!(packet_list[1] == token_two);
+    for (left, right) in packet_pairs.iter() {
+        packet_list.push(left.clone());
+        packet_list.push(right.clone());
+    }
+
+    packet_list.sort();
+
+    let token_one_index = packet_list.iter().position(|x| *x == token_one).unwrap() + 1;
+    let token_two_index = packet_list.iter().position(|x| *x == token_two).unwrap() + 1;
+    let key = token_one_index * token_two_index;
+    key.to_string()
+}
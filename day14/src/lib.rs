@@ -0,0 +1,230 @@
+use itertools::Itertools;
+use std::collections::HashSet;
+use utility::asciicast;
+use utility::catalog::{Info, Technique};
+use utility::identify;
+use utility::image::{self, Image, Rgb};
+use utility::json::Object;
+use utility::metrics;
+use utility::runner::{Day, Solution};
+use utility::trace;
+
+pub const DAY: Day = Day {
+    name: "day14",
+    input: "day14/input.txt",
+    //input: "day14/example-input.txt",
+    info: Info {
+        title: "Regolith Reservoir",
+        implemented: [true, true],
+        techniques: &[Technique::Simulation],
+        examples: &["day14/example-input.txt"],
+    },
+    solution: &Solution {
+        parse: parse_map,
+        part1,
+        part2,
+    },
+    is_input_line,
+    visualize: None,
+    scaling: None,
+};
+
+// Trace events (--trace):
+//   "rest": {"pos": [x, y]} each time a grain of sand comes to rest
+
+// A path of points like 498,4 -> 498,6 -> 496,6
+fn is_input_line(line: &str) -> bool {
+    line.split(" -> ")
+        .all(|point| identify::is_int_list(point, ",", 2))
+}
+
+fn parse_map(file_lines: &[String]) -> HashSet<(i64, i64)> {
+    let mut map = HashSet::new();
+    for line in file_lines {
+        let positions = line.split(" -> ").collect_vec();
+        let from = positions[0].split(',').collect_vec();
+        let mut from = (from[0].parse::<i64>().unwrap(), from[1].parse::<i64>().unwrap());
+        for to in positions.iter() {
+            let to = to.split(',').collect_vec();
+            let to = (to[0].parse::<i64>().unwrap(), to[1].parse::<i64>().unwrap());
+            if from.0 == to.0 {
+                if from.1 < to.1 {
+                    for y in from.1..=to.1 {
+                        map.insert((from.0, y));
+                    }
+                } else {
+                    for y in to.1..=from.1 {
+                        map.insert((from.0, y));
+                    }
+                }
+            } else {
+                assert_eq!(from.1, to.1, "Diagonal lines not supported");
+                if from.0 < to.0 {
+                    for x in from.0..=to.0 {
+                        map.insert((x, from.1));
+                    }
+                } else {
+                    for x in to.0..=from.0 {
+                        map.insert((x, from.1));
+                    }
+                }
+            }
+            from = to;
+        }
+    }
+
+    map
+}
+
+fn get_map_minmax(map: &HashSet<(i64, i64)>) -> ((i64, i64), (i64, i64)) {
+    let min_x = map.iter().map(|(x, _)| x).min().unwrap();
+    let max_x = map.iter().map(|(x, _)| x).max().unwrap();
+    let min_y = map.iter().map(|(_, y)| y).min().unwrap();
+    let max_y = map.iter().map(|(_, y)| y).max().unwrap();
+    ((*min_x, *min_y), (*max_x, *max_y))
+}
+
+fn drop_sand(map: &mut HashSet<(i64, i64)>, max_y: i64, x: i64, y: i64) -> bool {
+    let mut sand_pos = (x, y);
+    while sand_pos.1 <= max_y {
+        let next_pos1 = (sand_pos.0, sand_pos.1 + 1);
+        let next_pos2 = (sand_pos.0 - 1, sand_pos.1 + 1);
+        let next_pos3 = (sand_pos.0 + 1, sand_pos.1 + 1);
+
+        if !map.contains(&next_pos1) {
+            sand_pos = next_pos1;
+        } else if !map.contains(&next_pos2) {
+            sand_pos = next_pos2;
+        } else if !map.contains(&next_pos3) {
+            sand_pos = next_pos3;
+        } else {
+            break;
+        }
+        metrics::count("sand_steps");
+    }
+
+    if sand_pos.1 <= max_y {
+        map.insert(sand_pos);
+        metrics::count("sand_grains");
+        trace::event("rest", || Object::new().field("pos", sand_pos));
+        true
+    } else {
+        false
+    }
+}
+
+fn render_map(map: &HashSet<(i64, i64)>) -> String {
+    let ((min_x, min_y), (max_x, max_y)) = get_map_minmax(map);
+    let mut output = String::new();
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            if map.contains(&(x, y)) {
+                output.push('#');
+            } else {
+                output.push('.');
+            }
+        }
+        output.push('\n');
+    }
+    output
+}
+
+#[allow(dead_code)]
+fn print_map(map: &HashSet<(i64, i64)>) {
+    print!("{}", render_map(map));
+}
+
+// Rocks are gray and sand is yellow, within the given bounds
+fn render_image(
+    map: &HashSet<(i64, i64)>,
+    rocks: &HashSet<(i64, i64)>,
+    min: (i64, i64),
+    max: (i64, i64),
+) -> Image {
+    let width = (max.0 - min.0 + 1) as usize;
+    let height = (max.1 - min.1 + 1) as usize;
+    Image::from_fn(width, height, |x, y| {
+        let pos = (min.0 + x as i64, min.1 + y as i64);
+        if rocks.contains(&pos) {
+            Rgb::GRAY
+        } else if map.contains(&pos) {
+            Rgb::YELLOW
+        } else {
+            Rgb::BLACK
+        }
+    })
+}
+
+// Image frames are only written every so many grains, so each part's animation
+// is around a hundred files before --image-every thins it out further. The
+// final state is always written as a snapshot.
+const PART1_GRAINS_PER_FRAME: usize = 10;
+const PART2_GRAINS_PER_FRAME: usize = 250;
+
+fn part1(rocks: &HashSet<(i64, i64)>) -> String {
+    let mut map = rocks.clone();
+    let ((min_x, _), (max_x, max_y)) = get_map_minmax(&map);
+    let mut sand_count = 0;
+    while drop_sand(&mut map, max_y, 500, 0) {
+        sand_count += 1;
+        asciicast::frame(|| render_map(&map));
+        if sand_count % PART1_GRAINS_PER_FRAME == 0 {
+            image::frame("part1", || {
+                render_image(&map, rocks, (min_x, 0), (max_x, max_y))
+            });
+        }
+    }
+    image::snapshot("part1", || {
+        render_image(&map, rocks, (min_x, 0), (max_x, max_y))
+    });
+    sand_count.to_string()
+}
+
+fn drop_sand_p2(map: &mut HashSet<(i64, i64)>, max_y: i64, x: i64, y: i64) -> bool {
+    let mut sand_pos = (x, y);
+    if map.contains(&sand_pos) {
+        return false;
+    }
+
+    while sand_pos.1 < max_y - 1 {
+        let next_pos1 = (sand_pos.0, sand_pos.1 + 1);
+        let next_pos2 = (sand_pos.0 - 1, sand_pos.1 + 1);
+        let next_pos3 = (sand_pos.0 + 1, sand_pos.1 + 1);
+
+        if !map.contains(&next_pos1) {
+            sand_pos = next_pos1;
+        } else if !map.contains(&next_pos2) {
+            sand_pos = next_pos2;
+        } else if !map.contains(&next_pos3) {
+            sand_pos = next_pos3;
+        } else {
+            break;
+        }
+        metrics::count("sand_steps");
+    }
+
+    map.insert(sand_pos);
+    metrics::count("sand_grains");
+    trace::event("rest", || Object::new().field("pos", sand_pos));
+    true
+}
+
+
+fn part2(rocks: &HashSet<(i64, i64)>) -> String {
+    let mut map = rocks.clone();
+    let ((_, _), (_, max_y)) = get_map_minmax(&map);
+    // Sand can't spread further sideways than the floor is deep
+    let floor_y = max_y + 2;
+    let image_min = (500 - floor_y, 0);
+    let image_max = (500 + floor_y, floor_y);
+    let mut sand_count = 0;
+    while drop_sand_p2(&mut map, floor_y, 500, 0) {
+        sand_count += 1;
+        asciicast::frame(|| render_map(&map));
+        if sand_count % PART2_GRAINS_PER_FRAME == 0 {
+            image::frame("part2", || render_image(&map, rocks, image_min, image_max));
+        }
+    }
+    image::snapshot("part2", || render_image(&map, rocks, image_min, image_max));
+    sand_count.to_string()
+}
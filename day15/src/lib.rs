@@ -0,0 +1,195 @@
+use itertools::Itertools;
+use utility::catalog::{Info, Technique};
+use utility::interval::{Interval, IntervalSet};
+use utility::runner::{Day, Solution};
+use utility::scaling::{Complexity, Random, Scaling};
+
+pub const DAY: Day = Day {
+    name: "day15",
+    input: "day15/input.txt",
+    //input: "day15/example-input.txt",
+    info: Info {
+        title: "Beacon Exclusion Zone",
+        implemented: [true, true],
+        techniques: &[Technique::IntervalOverlap, Technique::DivideAndConquer],
+        examples: &["day15/example-input.txt"],
+    },
+    solution: &Solution {
+        parse: parse_map,
+        part1,
+        part2,
+    },
+    is_input_line,
+    visualize: None,
+    scaling: Some(Scaling {
+        generate,
+        sizes: &[50, 100, 200],
+        part1: Some(Complexity::Linearithmic),
+        part2: None,
+    }),
+};
+
+fn is_input_line(line: &str) -> bool {
+    line.starts_with("Sensor at x=") && line.contains(": closest beacon is at x=")
+}
+
+// Part 1 merges each sensor's stretch of the row, so n sensors take n log n
+// time to sort. Part 2 always searches the same area, so it isn't checked.
+fn generate(n: usize) -> Vec<String> {
+    let mut random = Random::new(15);
+    (0..n as i64)
+        .map(|i| {
+            let sensor = (i * 1000, 2000000 + random.range(-200, 200));
+            let beacon = (
+                sensor.0 + random.range(-300, 300),
+                sensor.1 + random.range(-300, 300),
+            );
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                sensor.0, sensor.1, beacon.0, beacon.1
+            )
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct SensorBeaconPair {
+    sensor: (i64, i64),
+    beacon: (i64, i64),
+    distance: i64,
+}
+
+impl SensorBeaconPair {
+    fn new(sensor: (i64, i64), beacon: (i64, i64)) -> Self {
+        let distance = (sensor.0 - beacon.0).abs() + (sensor.1 - beacon.1).abs();
+        Self {
+            sensor,
+            beacon,
+            distance,
+        }
+    }
+
+    fn from_string(s: &str) -> Self {
+        // The hackiest hack that ever did hack and it's not even fast or efficient
+        let sections = s.split(": closest beacon is at ").collect_vec();
+        let sensor_sections = sections[0].split(", ").collect_vec();
+        let beacon_sections = sections[1].split(", ").collect_vec();
+        let sensor_xstring = sensor_sections[0].split('=').collect_vec()[1];
+        let sensor_ystring = sensor_sections[1].split('=').collect_vec()[1];
+        let beacon_xstring = beacon_sections[0].split('=').collect_vec()[1];
+        let beacon_ystring = beacon_sections[1].split('=').collect_vec()[1];
+        let sensor = (
+            sensor_xstring.parse::<i64>().unwrap(),
+            sensor_ystring.parse::<i64>().unwrap(),
+        );
+        let beacon = (
+            beacon_xstring.parse::<i64>().unwrap(),
+            beacon_ystring.parse::<i64>().unwrap(),
+        );
+        Self::new(sensor, beacon)
+        // But it works
+    }
+
+    fn within_sensor_range(&self, other: (i64, i64)) -> bool {
+        let distance = (self.sensor.0 - other.0).abs() + (self.sensor.1 - other.1).abs();
+        distance <= self.distance
+    }
+
+    fn can_contain_unseen_points(&self, min: (i64, i64), max: (i64, i64)) -> bool {
+        let corners = [
+            (min.0, min.1),
+            (min.0, max.1),
+            (max.0, min.1),
+            (max.0, max.1),
+        ];
+        let largest_dist = corners
+            .iter()
+            .map(|corner| (corner.0 - self.sensor.0).abs() + (corner.1 - self.sensor.1).abs())
+            .max()
+            .unwrap();
+        largest_dist > self.distance
+    }
+}
+
+fn parse_map(file_lines: &[String]) -> Vec<SensorBeaconPair> {
+    file_lines
+        .iter()
+        .map(|line| SensorBeaconPair::from_string(line))
+        .collect_vec()
+}
+
+fn part1(map: &[SensorBeaconPair]) -> String {
+    // Change to 10 for example input
+    const LINE_Y: i64 = 2000000;
+
+    // Each sensor sees a stretch of the line that's narrower the further away
+    // it is
+    let mut in_range: IntervalSet = map
+        .iter()
+        .filter_map(|pair| {
+            let half_width = pair.distance - (pair.sensor.1 - LINE_Y).abs();
+            (half_width >= 0)
+                .then(|| Interval::new(pair.sensor.0 - half_width, pair.sensor.0 + half_width))
+        })
+        .collect();
+
+    // Occupied positions are the ones where a beacon or sensor exists
+    for pair in map {
+        for (x, y) in [pair.sensor, pair.beacon] {
+            if y == LINE_Y {
+                in_range.remove(Interval::point(x));
+            }
+        }
+    }
+    in_range.covered().to_string()
+}
+
+fn find_unseen_point(
+    map: &[SensorBeaconPair],
+    min: (i64, i64),
+    max: (i64, i64),
+) -> Option<(i64, i64)> {
+    let mut quadrant_stack = vec![(min, max)];
+
+    while let Some((min, max)) = quadrant_stack.pop() {
+        if min == max {
+            if map.iter().all(|pair| !pair.within_sensor_range(min)) {
+                return Some(min);
+            }
+        } else {
+            let mid = ((min.0 + max.0) / 2, (min.1 + max.1) / 2);
+            let quadrants = [
+                (min, mid),
+                ((mid.0 + 1, min.1), (max.0, mid.1)),
+                ((min.0, mid.1 + 1), (mid.0, max.1)),
+                ((mid.0 + 1, mid.1 + 1), max),
+            ];
+            for quadrant in quadrants.iter() {
+                if quadrant.0.0 > quadrant.1.0 || quadrant.0.1 > quadrant.1.1 {
+                    continue;
+                }
+
+                if map
+                    .iter()
+                    .all(|pair| pair.can_contain_unseen_points(quadrant.0, quadrant.1))
+                {
+                    quadrant_stack.push(*quadrant);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+fn part2(map: &[SensorBeaconPair]) -> String {
+    const MIN_XY: i64 = 0;
+    //const MAX_XY: i64 = 20;
+    const MAX_XY: i64 = 4000000;
+    let min = (MIN_XY, MIN_XY);
+    let max = (MAX_XY, MAX_XY);
+    let found_position = find_unseen_point(map, min, max).unwrap();
+
+    let tuning_freq = found_position.0 * MAX_XY + found_position.1;
+    tuning_freq.to_string()
+}
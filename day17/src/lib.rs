@@ -0,0 +1,288 @@
+use itertools::Itertools;
+use std::collections::HashSet;
+use utility::asciicast;
+use utility::catalog::{Info, Technique};
+use utility::cycle::{self, CycleDetector};
+use utility::identify;
+use utility::json::Object;
+use utility::runner::{Day, Solution};
+use utility::span;
+use utility::trace;
+use utility::visualizer::Simulation;
+
+pub const DAY: Day = Day {
+    name: "day17",
+    input: "day17/input.txt",
+    //input: "day17/example-input.txt",
+    info: Info {
+        title: "Pyroclastic Flow",
+        implemented: [true, true],
+        techniques: &[Technique::Simulation, Technique::CycleDetection],
+        examples: &["day17/example-input.txt"],
+    },
+    solution: &Solution {
+        parse: parse_jets,
+        part1,
+        part2,
+    },
+    is_input_line,
+    visualize: Some(visualize),
+    scaling: None,
+};
+
+// Trace events (--trace):
+//   "settle": {"rock": n, "cells": [[x, y], ...], "highest_y": y} when a rock
+//             comes to rest, counting rocks from 1
+
+fn is_input_line(line: &str) -> bool {
+    identify::is_made_of(line, "<>")
+}
+
+const DEBUG_PRINT: bool = false;
+const CHAMBER_WIDTH: i32 = 7;
+const SPAWN_X: i32 = 2;
+const SPAWN_Y: i32 = 3;
+// How many rows from the top part 2 compares when looking for a repeat
+const STATE_KEY_ROWS: usize = 64;
+const SHAPES: [(usize, [(i32, i32); 5]); 5] = {
+    [
+        (4, [(0, 0), (1, 0), (2, 0), (3, 0), (0, 0)]),
+        (5, [(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)]),
+        (5, [(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]),
+        (4, [(0, 0), (0, 1), (0, 2), (0, 3), (0, 0)]),
+        (4, [(0, 0), (0, 1), (1, 0), (1, 1), (0, 0)]),
+    ]
+};
+
+struct Chamber {
+    occupied: HashSet<(i32, i32)>,
+    last_shape_spawned: Vec<(i32, i32)>,
+    movements: Vec<i8>,
+    next_movement: usize,
+    down_next: bool,
+    next_shape: usize,
+    highest_y: i32,
+    num_stopped_rocks: usize,
+}
+
+impl Chamber {
+    fn new(line: &str) -> Chamber {
+        Chamber {
+            occupied: HashSet::new(),
+            last_shape_spawned: Vec::new(),
+            movements: line
+                .chars()
+                .map(|c| match c {
+                    '<' => -1,
+                    '>' => 1,
+                    _ => panic!("Invalid movement {}", c),
+                })
+                .collect_vec(),
+            next_movement: 0,
+            down_next: false,
+            next_shape: 0,
+            highest_y: 0,
+            num_stopped_rocks: 0,
+        }
+    }
+
+    fn step(&mut self) {
+        if !self.last_shape_spawned.is_empty() {
+            let movement: (i32, i32) = if self.down_next {
+                (0, -1)
+            } else {
+                let movement = self.movements[self.next_movement] as i32;
+                self.next_movement = (self.next_movement + 1) % self.movements.len();
+                (movement, 0)
+            };
+            if DEBUG_PRINT {
+                println!("Movement: {:?}", movement);
+            }
+            let can_move = self.last_shape_spawned.iter().all(|(x, y)| {
+                let new_x = x + movement.0;
+                let new_y = y + movement.1;
+                (0..CHAMBER_WIDTH).contains(&new_x)
+                    && new_y > 0
+                    && !self.occupied.contains(&(new_x, new_y))
+            });
+
+            if can_move {
+                for (x, y) in self.last_shape_spawned.iter_mut() {
+                    *x += movement.0;
+                    *y += movement.1;
+                }
+            } else if self.down_next {
+                // Shape settles
+                self.num_stopped_rocks += 1;
+                for (x, y) in self.last_shape_spawned.iter() {
+                    self.occupied.insert((*x, *y));
+                    self.highest_y = self.highest_y.max(*y);
+                }
+                trace::event("settle", || {
+                    Object::new()
+                        .field("rock", self.num_stopped_rocks)
+                        .field("cells", &self.last_shape_spawned)
+                        .field("highest_y", self.highest_y)
+                });
+
+                self.last_shape_spawned.clear();
+                self.down_next = false;
+                return;
+            }
+            self.down_next = !self.down_next;
+        } else {
+            // Spawn next shape
+            let (shape_size, shape) = SHAPES[self.next_shape];
+            self.next_shape = (self.next_shape + 1) % SHAPES.len();
+            let mut shape_spawned = Vec::new();
+
+            let base_y = self.highest_y + SPAWN_Y + 1;
+            if DEBUG_PRINT {
+                println!("Base y: {}", base_y);
+            }
+            for (x, y) in shape.iter().take(shape_size) {
+                let new_x = x + SPAWN_X;
+                let new_y = base_y + y;
+                shape_spawned.push((new_x, new_y));
+            }
+            self.last_shape_spawned = shape_spawned;
+        }
+    }
+
+    // Steps until the falling rock comes to rest
+    fn drop_rock(&mut self) {
+        let num_stopped_rocks = self.num_stopped_rocks;
+        while self.num_stopped_rocks == num_stopped_rocks {
+            self.step();
+        }
+    }
+
+    // Which shape and jet come next, and which cells are filled in the top
+    // rows, one bitmask per row. Rocks could in theory fall past those rows,
+    // so two chambers with the same key aren't guaranteed to carry on the same
+    // way, but no rock in the inputs tried lands more than 40 rows down.
+    fn state_key(&self) -> (usize, usize, [u8; STATE_KEY_ROWS]) {
+        let rows = std::array::from_fn(|depth| {
+            let y = self.highest_y - depth as i32;
+            (0..CHAMBER_WIDTH)
+                .filter(|&x| y <= 0 || self.occupied.contains(&(x, y)))
+                .fold(0, |row, x| row | 1 << x)
+        });
+        (self.next_shape, self.next_movement, rows)
+    }
+
+    fn render(&self) -> String {
+        let max_y = self.highest_y.max(
+            self.last_shape_spawned
+                .iter()
+                .copied()
+                .map(|(_, y)| y)
+                .max()
+                .unwrap_or(0),
+        );
+        let mut output = String::new();
+        for y in (0..=max_y).rev() {
+            for x in -1..CHAMBER_WIDTH + 1 {
+                if y == 0 {
+                    if x == -1 || x == CHAMBER_WIDTH {
+                        output.push('+');
+                    } else {
+                        output.push('-');
+                    }
+                } else if x == -1 || x == CHAMBER_WIDTH {
+                    output.push('|');
+                } else if self.occupied.contains(&(x, y)) {
+                    output.push('#');
+                } else if self.last_shape_spawned.contains(&(x, y)) {
+                    output.push('O');
+                } else {
+                    output.push('.');
+                }
+            }
+            output.push('\n');
+        }
+        output
+    }
+
+    fn print(&self) {
+        print!("{}", self.render());
+    }
+}
+
+impl Simulation for Chamber {
+    // Rocks keep falling forever, so this never finishes
+    fn step(&mut self) -> bool {
+        Chamber::step(self);
+        true
+    }
+
+    fn render(&self) -> String {
+        format!(
+            "Rocks: {} Height: {}\n{}",
+            self.num_stopped_rocks,
+            self.highest_y,
+            Chamber::render(self)
+        )
+    }
+}
+
+fn visualize(file_lines: &[String]) -> Box<dyn Simulation> {
+    Box::new(Chamber::new(&file_lines[0]))
+}
+
+fn parse_jets(file_lines: &[String]) -> String {
+    file_lines[0].clone()
+}
+
+fn part1(jets: &str) -> String {
+    let _span = span::enter("simulate");
+    let mut chamber = Chamber::new(jets);
+    while chamber.num_stopped_rocks < 2022 {
+        let new_spawn = chamber.last_shape_spawned.is_empty();
+        chamber.step();
+        if DEBUG_PRINT && new_spawn {
+            chamber.print();
+        }
+        if new_spawn {
+            asciicast::frame(|| chamber.render());
+        }
+    }
+
+    chamber.highest_y.to_string()
+}
+
+fn part2(jets: &str) -> String {
+    const NUM_SHAPES: u64 = 1000000000000;
+
+    // Drop rocks until the chamber's top looks the same as it did after some
+    // earlier rock, after which the heights gained repeat too
+    let simulate_span = span::enter("simulate");
+    let mut chamber = Chamber::new(jets);
+    let mut detector = CycleDetector::new();
+    detector.push(chamber.state_key());
+    let mut heights = vec![0];
+    let cycle = loop {
+        chamber.drop_rock();
+        heights.push(chamber.highest_y as i64);
+        if let Some(cycle) = detector.push(chamber.state_key()) {
+            break cycle;
+        }
+    };
+    drop(simulate_span);
+
+    let _span = span::enter("extrapolate");
+    cycle::extrapolate(&heights, cycle, NUM_SHAPES).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_JETS: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(part1(EXAMPLE_JETS), "3068");
+        assert_eq!(part2(EXAMPLE_JETS), "1514285714288");
+    }
+}
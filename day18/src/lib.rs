@@ -0,0 +1,134 @@
+use itertools::Itertools;
+use std::collections::HashSet;
+use utility::catalog::{Info, Technique};
+use utility::identify;
+use utility::image::{self, Image, Rgb};
+use utility::point::Point3;
+use utility::runner::{Day, Solution};
+
+pub const DAY: Day = Day {
+    name: "day18",
+    input: "day18/input.txt",
+    //input: "day18/example-input.txt",
+    info: Info {
+        title: "Boiling Boulders",
+        implemented: [true, true],
+        techniques: &[Technique::FloodFill],
+        examples: &["day18/example-input.txt"],
+    },
+    solution: &Solution {
+        parse: parse_input,
+        part1,
+        part2,
+    },
+    is_input_line,
+    visualize: None,
+    scaling: None,
+};
+
+fn is_input_line(line: &str) -> bool {
+    identify::is_int_list(line, ",", 3)
+}
+
+fn parse_cube(line: &str) -> Point3<i64> {
+    let segs = line.split(',').collect_vec();
+    Point3::new(
+        segs[0].parse().unwrap(),
+        segs[1].parse().unwrap(),
+        segs[2].parse().unwrap(),
+    )
+}
+
+fn parse_input(file_lines: &[String]) -> HashSet<Point3<i64>> {
+    file_lines.iter().map(|line| parse_cube(line)).collect()
+}
+
+fn total_surface_area(cubes: &HashSet<Point3<i64>>) -> i64 {
+    let mut surface_area = 0;
+    for &cube in cubes.iter() {
+        for adjacent_cube in cube.neighbors6() {
+            if !cubes.contains(&adjacent_cube) {
+                surface_area += 1;
+            }
+        }
+    }
+
+    surface_area
+}
+
+fn part1(cubes: &HashSet<Point3<i64>>) -> String {
+    let surface_area = total_surface_area(cubes);
+    surface_area.to_string()
+}
+
+fn part2(lava: &HashSet<Point3<i64>>) -> String {
+    let mut cubes = lava.clone();
+    let initial_surface_area = total_surface_area(lava);
+
+    // Take an exanded bounding box of the cube and flood-fill it with cubes
+    // This will leave only internal gaps.
+    let min_x = cubes.iter().map(|cube| cube.x).min().unwrap() - 2;
+    let max_x = cubes.iter().map(|cube| cube.x).max().unwrap() + 2;
+    let min_y = cubes.iter().map(|cube| cube.y).min().unwrap() - 2;
+    let max_y = cubes.iter().map(|cube| cube.y).max().unwrap() + 2;
+    let min_z = cubes.iter().map(|cube| cube.z).min().unwrap() - 2;
+    let max_z = cubes.iter().map(|cube| cube.z).max().unwrap() + 2;
+
+    for x in min_x..=max_x {
+        for y in min_y..=max_y {
+            cubes.insert(Point3::new(x, y, min_z));
+            cubes.insert(Point3::new(x, y, max_z));
+        }
+    }
+    for x in min_x..=max_x {
+        for z in min_z..=max_z {
+            cubes.insert(Point3::new(x, min_y, z));
+            cubes.insert(Point3::new(x, max_y, z));
+        }
+    }
+    for y in min_y..=max_y {
+        for z in min_z..=max_z {
+            cubes.insert(Point3::new(min_x, y, z));
+            cubes.insert(Point3::new(max_x, y, z));
+        }
+    }
+
+    let start = Point3::new(min_x + 1, min_y + 1, min_z + 1);
+    let mut queue = vec![start];
+    while let Some(cube) = queue.pop() {
+        if cubes.insert(cube) {
+            for adjacent_cube in cube.neighbors6() {
+                if !cubes.contains(&adjacent_cube) {
+                    queue.push(adjacent_cube);
+                }
+            }
+        }
+    }
+
+    // One frame per z layer: lava is red, the flooded outside is blue, and
+    // whatever is left black is an air pocket trapped inside
+    for z in min_z..=max_z {
+        image::frame("layers", || {
+            let width = (max_x - min_x + 1) as usize;
+            let height = (max_y - min_y + 1) as usize;
+            Image::from_fn(width, height, |x, y| {
+                let cube = Point3::new(min_x + x as i64, min_y + y as i64, z);
+                if lava.contains(&cube) {
+                    Rgb::RED
+                } else if cubes.contains(&cube) {
+                    Rgb::BLUE
+                } else {
+                    Rgb::BLACK
+                }
+            })
+        });
+    }
+
+    let expected_new_external_surface_area = 2 * (max_x - min_x + 1) * (max_y - min_y + 1)
+        + 2 * (max_x - min_x + 1) * (max_z - min_z + 1)
+        + 2 * (max_y - min_y + 1) * (max_z - min_z + 1);
+    let internal_surface_area = total_surface_area(&cubes) - expected_new_external_surface_area;
+    let external_surface_area = initial_surface_area - internal_surface_area;
+
+    external_surface_area.to_string()
+}
@@ -0,0 +1,138 @@
+use utility::catalog::{Info, Technique};
+use utility::runner::{Day, Solution};
+
+pub const DAY: Day = Day {
+    name: "day2",
+    input: "day2/input.txt",
+    //input: "day2/example_input.txt",
+    info: Info {
+        title: "Rock Paper Scissors",
+        implemented: [true, true],
+        techniques: &[Technique::ModularArithmetic],
+        examples: &[],
+    },
+    solution: &Solution {
+        parse: parse_rounds,
+        part1,
+        part2,
+    },
+    is_input_line,
+    visualize: None,
+    scaling: None,
+};
+
+fn is_input_line(line: &str) -> bool {
+    matches!(line.as_bytes(), [b'A'..=b'C', b' ', b'X'..=b'Z'])
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum RPSMove {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+// The second column of the strategy guide, which each part reads differently
+#[derive(Copy, Clone, PartialEq)]
+enum Column {
+    X,
+    Y,
+    Z,
+}
+
+fn parse_rounds(file_lines: &[String]) -> Vec<(RPSMove, Column)> {
+    file_lines
+        .iter()
+        .map(|line| {
+            let parts = line.split_ascii_whitespace().collect::<Vec<&str>>();
+            let enemy_move = match parts[0] {
+                "A" => RPSMove::Rock,
+                "B" => RPSMove::Paper,
+                "C" => RPSMove::Scissors,
+                _ => panic!("Invalid enemy move"),
+            };
+            let column = match parts[1] {
+                "X" => Column::X,
+                "Y" => Column::Y,
+                "Z" => Column::Z,
+                _ => panic!("Invalid my move"),
+            };
+            (enemy_move, column)
+        })
+        .collect()
+}
+
+fn beats(move_a: RPSMove, move_b: RPSMove) -> bool {
+    matches!(
+        (move_a, move_b),
+        (RPSMove::Rock, RPSMove::Scissors)
+            | (RPSMove::Paper, RPSMove::Rock)
+            | (RPSMove::Scissors, RPSMove::Paper)
+    )
+}
+
+fn move_score(m: RPSMove) -> u32 {
+    match m {
+        RPSMove::Rock => 1,
+        RPSMove::Paper => 2,
+        RPSMove::Scissors => 3,
+    }
+}
+
+fn part1(rounds: &[(RPSMove, Column)]) -> String {
+    let mut score = 0;
+    for &(enemy_move, column) in rounds.iter() {
+        let my_move = match column {
+            Column::X => RPSMove::Rock,
+            Column::Y => RPSMove::Paper,
+            Column::Z => RPSMove::Scissors,
+        };
+        score += move_score(my_move)
+            + if enemy_move == my_move {
+                3
+            } else if beats(my_move, enemy_move) {
+                6
+            } else {
+                0
+            };
+    }
+
+    format!("{}", score)
+}
+
+fn winning_move(m: RPSMove) -> RPSMove {
+    match m {
+        RPSMove::Rock => RPSMove::Paper,
+        RPSMove::Paper => RPSMove::Scissors,
+        RPSMove::Scissors => RPSMove::Rock,
+    }
+}
+
+fn losing_move(m: RPSMove) -> RPSMove {
+    match m {
+        RPSMove::Rock => RPSMove::Scissors,
+        RPSMove::Paper => RPSMove::Rock,
+        RPSMove::Scissors => RPSMove::Paper,
+    }
+}
+
+fn part2(rounds: &[(RPSMove, Column)]) -> String {
+    let mut score = 0;
+    for &(enemy_move, column) in rounds.iter() {
+        let my_move = match column {
+            Column::X => losing_move(enemy_move),
+            Column::Y => enemy_move,
+            Column::Z => winning_move(enemy_move),
+        };
+        score += move_score(my_move)
+            + if enemy_move == my_move {
+                3
+            } else if beats(my_move, enemy_move) {
+                6
+            } else {
+                0
+            };
+    }
+
+    format!("{}", score)
+}
@@ -0,0 +1,142 @@
+use utility::catalog::{Info, Technique};
+use utility::identify;
+use utility::runner::{Day, Solution};
+use utility::scaling::{Complexity, Random, Scaling};
+
+pub const DAY: Day = Day {
+    name: "day20",
+    input: "day20/input.txt",
+    //input: "day20/example-input.txt",
+    info: Info {
+        title: "Grove Positioning System",
+        implemented: [true, true],
+        techniques: &[Technique::Simulation],
+        examples: &["day20/example-input.txt"],
+    },
+    solution: &Solution {
+        parse: parse_list,
+        part1,
+        part2,
+    },
+    is_input_line,
+    visualize: None,
+    scaling: Some(Scaling {
+        generate,
+        sizes: &[500, 1000, 2000],
+        part1: Some(Complexity::Quadratic),
+        part2: Some(Complexity::Quadratic),
+    }),
+};
+
+fn is_input_line(line: &str) -> bool {
+    identify::is_int(line)
+}
+
+// mix_item finds each item with a linear scan and then moves it one swap at a
+// time, so mixing n numbers of size up to n is quadratic
+fn generate(n: usize) -> Vec<String> {
+    let mut random = Random::new(20);
+    let mut numbers = (0..n)
+        .map(|_| random.range(-(n as i64), n as i64))
+        .map(|number| if number == 0 { 1 } else { number })
+        .collect::<Vec<_>>();
+    numbers[n / 2] = 0;
+    numbers.iter().map(|number| number.to_string()).collect()
+}
+
+#[derive(Debug, Clone)]
+struct Item {
+    value: i64,
+    orig_index: usize,
+}
+
+fn parse_list(file_lines: &[String]) -> Vec<Item> {
+    let mut list = vec![];
+    for (i, line) in file_lines.iter().enumerate() {
+        let num = line.parse::<i64>().unwrap();
+        list.push(Item {
+            value: num,
+            orig_index: i,
+        });
+    }
+    list
+}
+
+fn mix_item(items: &mut [Item], orig_index: usize) {
+    // Find the item's current index which has the original index
+    let current_index = items.iter().position(|x| x.orig_index == orig_index).unwrap();
+
+    let value = items[current_index].value;
+    if value == 0 {
+        return;
+    }
+
+    let swap_mod = (items.len() - 1) as i64;
+    if value > 0 {
+        let num_swaps = (value % swap_mod) as usize;
+        for i in 0..num_swaps {
+            let index0 = (current_index + i) % items.len();
+            let index1 = (current_index + i + 1) % items.len();
+            items.swap(index0, index1);
+        }
+    } else {
+        let num_swaps = (-value % swap_mod) as usize;
+        for i in 0..num_swaps {
+            let index0 = (current_index + items.len() * 2 - i) % items.len();
+            let index1 = (current_index + items.len() * 2 - i - 1) % items.len();
+            items.swap(index0, index1);
+        }
+    }
+}
+
+#[allow(dead_code)]
+fn print_list(items: &[Item]) {
+    for item in items.iter() {
+        print!("{} ", item.value);
+    }
+    println!();
+}
+
+fn part1(items: &[Item]) -> String {
+    let mut items = items.to_vec();
+
+    for i in 0..items.len() {
+        mix_item(&mut items, i);
+    }
+
+    // Find the index of value 0
+    let zero_index = items.iter().position(|x| x.value == 0).unwrap();
+
+    let value1000 = items[(zero_index + 1000) % items.len()].value;
+    let value2000 = items[(zero_index + 2000) % items.len()].value;
+    let value3000 = items[(zero_index + 3000) % items.len()].value;
+
+    let value_sum = value1000 + value2000 + value3000;
+
+    value_sum.to_string()
+}
+
+fn part2(items: &[Item]) -> String {
+    const DECRYPTION_KEY: i64 = 811589153;
+    let mut items = items.to_vec();
+    for item in items.iter_mut() {
+        item.value *= DECRYPTION_KEY;
+    }
+
+    for _ in 0..10 {
+        for i in 0..items.len() {
+            mix_item(&mut items, i);
+        }
+    }
+
+    // Find the index of value 0
+    let zero_index = items.iter().position(|x| x.value == 0).unwrap();
+
+    let value1000 = items[(zero_index + 1000) % items.len()].value;
+    let value2000 = items[(zero_index + 2000) % items.len()].value;
+    let value3000 = items[(zero_index + 3000) % items.len()].value;
+
+    let value_sum = value1000 + value2000 + value3000;
+
+    value_sum.to_string()
+}
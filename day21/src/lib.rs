@@ -0,0 +1,215 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use utility::catalog::{Info, Technique};
+use utility::dot;
+use utility::runner::{Day, Solution};
+
+pub const DAY: Day = Day {
+    name: "day21",
+    input: "day21/input.txt",
+    //input: "day21/example-input.txt",
+    info: Info {
+        title: "Monkey Math",
+        implemented: [true, true],
+        techniques: &[Technique::TreeWalk, Technique::BinarySearch],
+        examples: &["day21/example-input.txt"],
+    },
+    solution: &Solution {
+        parse: parse_lines,
+        part1,
+        part2,
+    },
+    is_input_line,
+    visualize: None,
+    scaling: None,
+};
+
+fn is_input_line(line: &str) -> bool {
+    RE_SCALAR.is_match(line) || RE_OP.is_match(line)
+}
+
+lazy_static! {
+    static ref RE_SCALAR: Regex = Regex::new(r"([a-z]+): (\d+)$").unwrap();
+    static ref RE_OP: Regex = Regex::new(r"([a-z]+): ([a-z]+) ([+\-*/]) ([a-z]+)$").unwrap();
+}
+
+#[derive(Debug, Clone)]
+enum Op {
+    Scalar(i64),
+    Add(usize, usize),
+    Sub(usize, usize),
+    Mul(usize, usize),
+    Div(usize, usize),
+}
+
+impl Op {
+    fn ids(&self) -> (usize, usize) {
+        match self {
+            Op::Scalar(_) => (0, 0),
+            Op::Add(left, right) => (*left, *right),
+            Op::Sub(left, right) => (*left, *right),
+            Op::Mul(left, right) => (*left, *right),
+            Op::Div(left, right) => (*left, *right),
+        }
+    }
+}
+
+fn parse_lines(file_lines: &[String]) -> (Vec<Op>, HashMap<String, usize>) {
+    let mut name_lookup: HashMap<String, usize> = HashMap::new();
+
+    // Populate the name lookup first
+    for (id, line) in file_lines.iter().enumerate() {
+        if let Some(caps) = RE_SCALAR.captures(line) {
+            let name = &caps[1];
+            name_lookup.insert(name.to_owned(), id);
+        } else if let Some(caps) = RE_OP.captures(line) {
+            let name = &caps[1];
+            name_lookup.insert(name.to_owned(), id);
+        } else {
+            panic!("Failed to parse line: {}", line);
+        }
+    }
+
+    let mut ops = vec![Op::Scalar(0); name_lookup.len()];
+    for line in file_lines {
+        if let Some(caps) = RE_SCALAR.captures(line) {
+            let id = name_lookup[&caps[1]];
+            let value = caps[2].parse::<i64>().unwrap();
+            ops[id] = Op::Scalar(value);
+        } else if let Some(caps) = RE_OP.captures(line) {
+            let id = name_lookup[&caps[1]];
+
+            let op_str = &caps[3];
+            let left = name_lookup[&caps[2]];
+            let right = name_lookup[&caps[4]];
+            let op = match op_str {
+                "+" => Op::Add(left, right),
+                "-" => Op::Sub(left, right),
+                "*" => Op::Mul(left, right),
+                "/" => Op::Div(left, right),
+                _ => panic!("Unknown op: {}", op_str),
+            };
+            ops[id] = op;
+        } else {
+            panic!("Failed to parse line: {}", line);
+        }
+    }
+    (ops, name_lookup)
+}
+
+fn evaluate(ops: &[Op], id: usize) -> i64 {
+    match &ops[id] {
+        Op::Scalar(value) => *value,
+        Op::Add(left, right) => evaluate(ops, *left) + evaluate(ops, *right),
+        Op::Sub(left, right) => evaluate(ops, *left) - evaluate(ops, *right),
+        Op::Mul(left, right) => evaluate(ops, *left) * evaluate(ops, *right),
+        Op::Div(left, right) => evaluate(ops, *left) / evaluate(ops, *right),
+    }
+}
+
+// The monkeys from id down to target, if target is one of the monkeys id depends on
+fn find_chain(ops: &[Op], id: usize, target: usize) -> Option<Vec<usize>> {
+    if id == target {
+        return Some(vec![id]);
+    }
+    if let Op::Scalar(_) = ops[id] {
+        return None;
+    }
+
+    let (left, right) = ops[id].ids();
+    let mut chain = find_chain(ops, left, target).or_else(|| find_chain(ops, right, target))?;
+    chain.insert(0, id);
+    Some(chain)
+}
+
+// Each monkey points at the monkeys it waits on
+fn monkey_graph(ops: &[Op], name_lookup: &HashMap<String, usize>, chain: &[usize]) -> dot::Graph {
+    let mut names = vec![""; ops.len()];
+    for (name, id) in name_lookup.iter() {
+        names[*id] = name;
+    }
+    let on_chain = chain.iter().copied().collect::<HashSet<_>>();
+
+    let mut graph = dot::Graph::directed("day21");
+    graph.node_defaults(&[("shape", "box")]);
+    for (id, op) in ops.iter().enumerate() {
+        let label = match op {
+            Op::Scalar(value) => format!("{}\n{}", names[id], value),
+            Op::Add(..) => format!("{}\n+", names[id]),
+            Op::Sub(..) => format!("{}\n-", names[id]),
+            Op::Mul(..) => format!("{}\n*", names[id]),
+            Op::Div(..) => format!("{}\n/", names[id]),
+        };
+        let mut attributes = vec![("label", label.as_str())];
+        if on_chain.contains(&id) {
+            attributes.extend([("style", "filled"), ("fillcolor", "orange")]);
+        }
+        graph.node(names[id], &attributes);
+
+        if let Op::Scalar(_) = op {
+            continue;
+        }
+        let (left, right) = op.ids();
+        for input in [left, right] {
+            let attributes: &[(&str, &str)] = if on_chain.contains(&id) && on_chain.contains(&input)
+            {
+                &[("color", "orange"), ("penwidth", "3")]
+            } else {
+                &[]
+            };
+            graph.edge(names[id], names[input], attributes);
+        }
+    }
+    graph
+}
+
+fn part1((ops, name_lookup): &(Vec<Op>, HashMap<String, usize>)) -> String {
+    let root_val = evaluate(ops, name_lookup["root"]);
+
+    root_val.to_string()
+}
+
+fn part2((ops, name_lookup): &(Vec<Op>, HashMap<String, usize>)) -> String {
+    let root_id = name_lookup["root"];
+    let humn_id = name_lookup["humn"];
+
+    dot::export(|highlight| {
+        let chain = if highlight {
+            find_chain(ops, root_id, humn_id).unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+        monkey_graph(ops, name_lookup, &chain)
+    });
+
+    // Modify the root to subtract left from right. 0 means we win.
+    let mut ops = ops.clone();
+    let (left, right) = ops[root_id].ids();
+    ops[root_id] = Op::Sub(left, right);
+
+    let mut lower_bound: i64 = 0;
+    let mut upper_bound: i64 = 0;
+    let mut humn_val: i64 = 1;
+    loop {
+        ops[humn_id] = Op::Scalar(humn_val);
+        let root_val = evaluate(&ops, root_id);
+        if root_val == 0 {
+            return humn_val.to_string();
+        }
+
+        if root_val < 0 {
+            lower_bound = humn_val;
+        } else {
+            upper_bound = humn_val;
+        }
+
+        if lower_bound == 0 || upper_bound == 0 {
+            humn_val *= 2;
+        } else if lower_bound == upper_bound {
+            return "No solution found".to_owned();
+        } else {
+            humn_val = (lower_bound + upper_bound) / 2;
+        }
+    }
+}
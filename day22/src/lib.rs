@@ -0,0 +1,431 @@
+use std::collections::HashMap;
+use utility::asciicast;
+use utility::catalog::{Info, Technique};
+use utility::dir::Dir4;
+use utility::identify;
+use utility::runner::{Day, Solution};
+
+pub const DAY: Day = Day {
+    name: "day22",
+    input: "day22/input.txt",
+    //input: "day22/example-input.txt",
+    info: Info {
+        title: "Monkey Map",
+        implemented: [true, true],
+        techniques: &[Technique::Simulation],
+        examples: &["day22/example-input.txt"],
+    },
+    solution: &Solution {
+        parse: parse_lines,
+        part1,
+        part2,
+    },
+    is_input_line,
+    visualize: None,
+    scaling: None,
+};
+
+// The map, a blank line, then the path to follow
+fn is_input_line(line: &str) -> bool {
+    line.is_empty()
+        || identify::is_made_of(line, " .#")
+        || identify::is_made_of(line, "0123456789LR")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Cursor {
+    pos: (i32, i32),
+    dir: Dir4,
+}
+
+impl Cursor {
+    fn password(&self) -> i64 {
+        let facing = self.dir.code() as i64;
+        (self.pos.0 + 1) as i64 * 1000 + (self.pos.1 + 1) as i64 * 4 + facing
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Tile {
+    Open,
+    Wall,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Instruction {
+    M(i32),
+    R,
+    L,
+}
+
+impl Instruction {
+    fn move_cursor(cursor: Cursor, tiles: &HashMap<(i32, i32), Tile>, part1: bool) -> Cursor {
+        let mut new_cursor = cursor;
+        new_cursor.pos = cursor.dir.step_row_col(cursor.pos);
+
+        match tiles.get(&new_cursor.pos) {
+            Some(Tile::Wall) => new_cursor = cursor,
+            Some(Tile::Open) => (),
+            _ => {
+                if part1 {
+                    match cursor.dir {
+                        Dir4::Right => {
+                            new_cursor.pos.1 = tiles
+                                .keys()
+                                .copied()
+                                .filter(|(x, _)| *x == cursor.pos.0)
+                                .map(|(_, y)| y)
+                                .min()
+                                .unwrap()
+                        }
+                        Dir4::Down => {
+                            new_cursor.pos.0 = tiles
+                                .keys()
+                                .copied()
+                                .filter(|(_, y)| *y == cursor.pos.1)
+                                .map(|(x, _)| x)
+                                .min()
+                                .unwrap()
+                        }
+                        Dir4::Left => {
+                            new_cursor.pos.1 = tiles
+                                .keys()
+                                .copied()
+                                .filter(|(x, _)| *x == cursor.pos.0)
+                                .map(|(_, y)| y)
+                                .max()
+                                .unwrap()
+                        }
+                        Dir4::Up => {
+                            new_cursor.pos.0 = tiles
+                                .keys()
+                                .copied()
+                                .filter(|(_, y)| *y == cursor.pos.1)
+                                .map(|(x, _)| x)
+                                .max()
+                                .unwrap()
+                        }
+                    }
+                } else {
+                    new_cursor = Self::move_cube_edge(cursor);
+                }
+
+                if tiles[&new_cursor.pos] == Tile::Wall {
+                    new_cursor = cursor;
+                }
+            }
+        }
+
+        new_cursor
+    }
+
+    fn move_cube_edge(cursor: Cursor) -> Cursor {
+        // This is specific to my input, which looks like this:
+        //  12
+        //  3
+        // 45
+        // 6
+        const FACE_SIZE: i32 = 50;
+        let current_face = match (cursor.pos.0 / FACE_SIZE, cursor.pos.1 / FACE_SIZE) {
+            (0, 1) => 1,
+            (0, 2) => 2,
+            (1, 1) => 3,
+            (2, 0) => 4,
+            (2, 1) => 5,
+            (3, 0) => 6,
+            _ => panic!("Unexpected position: {:?}", cursor.pos),
+        };
+
+        let mut new_cursor = cursor;
+        new_cursor.pos = cursor.dir.step_row_col(cursor.pos);
+        match current_face {
+            1 => {
+                match cursor.dir {
+                    Dir4::Right => {
+                        // Do nothing
+                    }
+                    Dir4::Down => {
+                        // Do nothing
+                    }
+                    Dir4::Left => {
+                        new_cursor.dir = Dir4::Right;
+                        new_cursor.pos.0 = FACE_SIZE * 3 - cursor.pos.0 - 1;
+                        new_cursor.pos.1 = 0;
+                    }
+                    Dir4::Up => {
+                        new_cursor.dir = Dir4::Right;
+                        new_cursor.pos.0 = cursor.pos.1 + FACE_SIZE * 2;
+                        new_cursor.pos.1 = 0;
+                    }
+                }
+            }
+            2 => {
+                match cursor.dir {
+                    Dir4::Right => {
+                        new_cursor.dir = Dir4::Left;
+                        new_cursor.pos.0 = FACE_SIZE * 3 - cursor.pos.0 - 1;
+                        new_cursor.pos.1 = cursor.pos.1 - FACE_SIZE;
+                    }
+                    Dir4::Down => {
+                        new_cursor.dir = Dir4::Left;
+                        new_cursor.pos.0 = cursor.pos.1 - FACE_SIZE;
+                        new_cursor.pos.1 = FACE_SIZE * 2 - 1;
+                    }
+                    Dir4::Left => {
+                        // Do nothing
+                    }
+                    Dir4::Up => {
+                        new_cursor.dir = Dir4::Up;
+                        new_cursor.pos.0 = FACE_SIZE * 4 - 1;
+                        new_cursor.pos.1 = cursor.pos.1 - FACE_SIZE * 2;
+                    }
+                }
+            }
+            3 => {
+                match cursor.dir {
+                    Dir4::Right => {
+                        new_cursor.dir = Dir4::Up;
+                        new_cursor.pos.0 = FACE_SIZE - 1;
+                        new_cursor.pos.1 = cursor.pos.0 + FACE_SIZE;
+                    }
+                    Dir4::Down => {
+                        // Do nothing
+                    }
+                    Dir4::Left => {
+                        new_cursor.dir = Dir4::Down;
+                        new_cursor.pos.0 = FACE_SIZE * 2;
+                        new_cursor.pos.1 = cursor.pos.0 - FACE_SIZE;
+                    }
+                    Dir4::Up => {
+                        // Do nothing
+                    }
+                }
+            }
+            4 => {
+                match cursor.dir {
+                    Dir4::Right => {
+                        // Do nothing
+                    }
+                    Dir4::Down => {
+                        // Do nothing
+                    }
+                    Dir4::Left => {
+                        new_cursor.dir = Dir4::Right;
+                        new_cursor.pos.0 = 3 * FACE_SIZE - cursor.pos.0 - 1;
+                        new_cursor.pos.1 = FACE_SIZE;
+                    }
+                    Dir4::Up => {
+                        new_cursor.dir = Dir4::Right;
+                        new_cursor.pos.0 = cursor.pos.1 + FACE_SIZE;
+                        new_cursor.pos.1 = FACE_SIZE;
+                    }
+                }
+            }
+            5 => {
+                match cursor.dir {
+                    Dir4::Right => {
+                        new_cursor.dir = Dir4::Left;
+                        new_cursor.pos.0 = 3 * FACE_SIZE - cursor.pos.0 - 1;
+                        new_cursor.pos.1 = FACE_SIZE * 3 - 1;
+                    }
+                    Dir4::Down => {
+                        new_cursor.dir = Dir4::Left;
+                        new_cursor.pos.0 = cursor.pos.1 + FACE_SIZE * 2;
+                        new_cursor.pos.1 = FACE_SIZE - 1;
+                    }
+                    Dir4::Left => {
+                        // Do nothing
+                    }
+                    Dir4::Up => {
+                        // Do nothing
+                    }
+                }
+            }
+            6 => {
+                match cursor.dir {
+                    Dir4::Right => {
+                        new_cursor.dir = Dir4::Up;
+                        new_cursor.pos.0 = FACE_SIZE * 3 - 1;
+                        new_cursor.pos.1 = cursor.pos.0 - 2 * FACE_SIZE;
+                    }
+                    Dir4::Down => {
+                        new_cursor.dir = Dir4::Down;
+                        new_cursor.pos.0 = 0;
+                        new_cursor.pos.1 = cursor.pos.1 + FACE_SIZE * 2;
+                    }
+                    Dir4::Left => {
+                        new_cursor.dir = Dir4::Down;
+                        new_cursor.pos.0 = 0;
+                        new_cursor.pos.1 = cursor.pos.0 - FACE_SIZE * 2;
+                    }
+                    Dir4::Up => {
+                        // Do nothing
+                    }
+                }
+            }
+            _ => panic!("Unexpected face: {}", current_face),
+        }
+
+        new_cursor
+    }
+
+    fn execute(&self, cursor: &Cursor, tiles: &HashMap<(i32, i32), Tile>, part1: bool) -> Cursor {
+        match self {
+            Instruction::M(dist) => {
+                let mut new_cursor = *cursor;
+                for _ in 0..*dist {
+                    new_cursor = Self::move_cursor(new_cursor, tiles, part1);
+                }
+                new_cursor
+            }
+            Instruction::R => Cursor {
+                pos: cursor.pos,
+                dir: cursor.dir.turn_right(),
+            },
+            Instruction::L => Cursor {
+                pos: cursor.pos,
+                dir: cursor.dir.turn_left(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParsedData {
+    tiles: HashMap<(i32, i32), Tile>,
+    instructions: Vec<Instruction>,
+    start_pos: (i32, i32),
+}
+
+fn parse_lines(file_lines: &[String]) -> ParsedData {
+    let mut tiles = HashMap::new();
+    let mut start_pos = (-1, -1);
+    for (row, line) in file_lines.iter().enumerate().take(file_lines.len() - 2) {
+        for (col, cur_char) in line.chars().enumerate() {
+            let tile = match cur_char {
+                '#' => Tile::Wall,
+                '.' => Tile::Open,
+                ' ' => continue,
+                _ => panic!("Unexpected character: {}", cur_char),
+            };
+
+            if start_pos == (-1, -1) {
+                start_pos = (row as i32, col as i32);
+            }
+
+            tiles.insert((row as i32, col as i32), tile);
+        }
+    }
+
+    let mut instructions = Vec::new();
+    let instructions_line = file_lines[file_lines.len() - 1].as_bytes();
+    let mut instruction_idx = 0;
+    while instruction_idx < instructions_line.len() {
+        let instruction = if instructions_line[instruction_idx] >= b'0'
+            && instructions_line[instruction_idx] <= b'9'
+        {
+            let mut dist_str = String::new();
+            while instruction_idx < instructions_line.len()
+                && instructions_line[instruction_idx] >= b'0'
+                && instructions_line[instruction_idx] <= b'9'
+            {
+                dist_str.push(instructions_line[instruction_idx] as char);
+                instruction_idx += 1;
+            }
+            let dist = dist_str.parse().unwrap();
+            Instruction::M(dist)
+        } else {
+            let instruction = match instructions_line[instruction_idx] as char {
+                'R' => Instruction::R,
+                'L' => Instruction::L,
+                _ => panic!(
+                    "Unexpected character: {}",
+                    instructions_line[instruction_idx] as char
+                ),
+            };
+            instruction_idx += 1;
+            instruction
+        };
+
+        instructions.push(instruction);
+    }
+
+    ParsedData {
+        tiles,
+        instructions,
+        start_pos,
+    }
+}
+
+fn render_map(cursor: &Cursor, tiles: &HashMap<(i32, i32), Tile>) -> String {
+    let min_x = tiles.keys().copied().map(|(x, _)| x).min().unwrap();
+    let max_x = tiles.keys().copied().map(|(x, _)| x).max().unwrap();
+    let min_y = tiles.keys().copied().map(|(_, y)| y).min().unwrap();
+    let max_y = tiles.keys().copied().map(|(_, y)| y).max().unwrap();
+
+    let mut output = String::new();
+    for x in min_x..=max_x {
+        for y in min_y..=max_y {
+            if (x, y) == cursor.pos {
+                output.push(cursor.dir.arrow());
+            } else {
+                output.push(match tiles.get(&(x, y)) {
+                    Some(Tile::Wall) => '#',
+                    Some(Tile::Open) => '.',
+                    _ => ' ',
+                });
+            }
+        }
+        output.push('\n');
+    }
+    output
+}
+
+#[allow(dead_code)]
+fn print_map(cursor: &Cursor, tiles: &HashMap<(i32, i32), Tile>) {
+    print!("{}", render_map(cursor, tiles));
+}
+
+fn part1(parsed: &ParsedData) -> String {
+    let ParsedData {
+        tiles,
+        instructions,
+        start_pos,
+    } = parsed;
+    let mut cursor = Cursor {
+        pos: *start_pos,
+        dir: Dir4::Right,
+    };
+    asciicast::frame(|| render_map(&cursor, tiles));
+
+    for instruction in instructions.iter() {
+        cursor = instruction.execute(&cursor, tiles, true);
+        //println!("{:?}", instruction);
+        asciicast::frame(|| render_map(&cursor, tiles));
+    }
+    let password = cursor.password();
+
+    password.to_string()
+}
+
+fn part2(parsed: &ParsedData) -> String {
+    let ParsedData {
+        tiles,
+        instructions,
+        start_pos,
+    } = parsed;
+    let mut cursor = Cursor {
+        pos: *start_pos,
+        dir: Dir4::Right,
+    };
+
+    asciicast::frame(|| render_map(&cursor, tiles));
+
+    for instruction in instructions.iter() {
+        cursor = instruction.execute(&cursor, tiles, false);
+        asciicast::frame(|| render_map(&cursor, tiles));
+    }
+    let password = cursor.password();
+
+    password.to_string()
+}
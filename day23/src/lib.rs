@@ -0,0 +1,232 @@
+use std::collections::{HashMap, HashSet};
+use utility::asciicast;
+use utility::catalog::{Info, Technique};
+use utility::dir::{Dir4, Dir8};
+use utility::identify;
+use utility::image::{self, Image, Rgb};
+use utility::json::Object;
+use utility::runner::{Day, Solution};
+use utility::trace;
+use utility::visualizer::Simulation;
+
+pub const DAY: Day = Day {
+    name: "day23",
+    input: "day23/input.txt",
+    //input: "day23/example-input.txt",
+    info: Info {
+        title: "Unstable Diffusion",
+        implemented: [true, true],
+        techniques: &[Technique::Simulation],
+        examples: &["day23/example-input.txt"],
+    },
+    solution: &Solution {
+        parse: parse_lines,
+        part1,
+        part2,
+    },
+    is_input_line,
+    visualize: Some(visualize),
+    scaling: None,
+};
+
+// Trace events (--trace):
+//   "round": {"ordering": n, "proposed": [[[r, c], [r, c]], ...], "accepted": [...]}
+//            once per round, listing each move as [from, to]. Accepted moves are
+//            the proposals whose destination no other elf proposed.
+
+fn is_input_line(line: &str) -> bool {
+    identify::is_made_of(line, ".#")
+}
+
+fn parse_lines(file_lines: &[String]) -> HashSet<(i64, i64)> {
+    let mut map = HashSet::new();
+    for (r, line) in file_lines.iter().enumerate() {
+        for (c, ch) in line.chars().enumerate() {
+            if ch == '#' {
+                map.insert((r as i64, c as i64));
+            }
+        }
+    }
+    map
+}
+
+fn render_map(map: &HashSet<(i64, i64)>) -> String {
+    let min_x = map.iter().map(|(r, _)| r).min().unwrap();
+    let max_x = map.iter().map(|(r, _)| r).max().unwrap();
+    let min_y = map.iter().map(|(_, c)| c).min().unwrap();
+    let max_y = map.iter().map(|(_, c)| c).max().unwrap();
+    let mut output = String::new();
+    for r in *min_x..=*max_x {
+        for c in *min_y..=*max_y {
+            if map.contains(&(r, c)) {
+                output.push('#');
+            } else {
+                output.push('.');
+            }
+        }
+        output.push('\n');
+    }
+    output
+}
+
+fn map_bounds(map: &HashSet<(i64, i64)>) -> ((i64, i64), (i64, i64)) {
+    let min_r = map.iter().map(|(r, _)| *r).min().unwrap();
+    let max_r = map.iter().map(|(r, _)| *r).max().unwrap();
+    let min_c = map.iter().map(|(_, c)| *c).min().unwrap();
+    let max_c = map.iter().map(|(_, c)| *c).max().unwrap();
+    ((min_r, min_c), (max_r, max_c))
+}
+
+fn render_image(map: &HashSet<(i64, i64)>, min: (i64, i64), max: (i64, i64)) -> Image {
+    let width = (max.1 - min.1 + 1) as usize;
+    let height = (max.0 - min.0 + 1) as usize;
+    Image::from_fn(width, height, |c, r| {
+        if map.contains(&(min.0 + r as i64, min.1 + c as i64)) {
+            Rgb::GREEN
+        } else {
+            Rgb::BLACK
+        }
+    })
+}
+
+fn print_map(map: &HashSet<(i64, i64)>) {
+    println!("{}", render_map(map));
+}
+
+// Each round the elves try north, south, west and east in this order, starting
+// one further along than the round before
+const PROPOSAL_ORDER: [Dir4; 4] = [Dir4::Up, Dir4::Down, Dir4::Left, Dir4::Right];
+
+fn execute_round(map: &mut HashSet<(i64, i64)>, ordering: usize) -> bool {
+    let mut proposed_moves: Vec<((i64, i64), (i64, i64))> = Vec::new();
+    let mut proposed_desintations: HashMap<(i64, i64), usize> = HashMap::new();
+    for pos in map.iter() {
+        let is_empty = |dir: Dir8| !map.contains(&dir.step_row_col(*pos));
+        if Dir8::ALL.into_iter().all(is_empty) {
+            continue;
+        }
+
+        // An elf only moves a way if nobody is there or on the diagonals either side
+        let can_move = |dir: Dir4| {
+            let dir = Dir8::from(dir);
+            [dir.rotate(-1), dir, dir.rotate(1)]
+                .into_iter()
+                .all(is_empty)
+        };
+        for ordering_offset in 0..4 {
+            let dir = PROPOSAL_ORDER[(ordering + ordering_offset) % 4];
+            if can_move(dir) {
+                let dest = dir.step_row_col(*pos);
+                proposed_moves.push((*pos, dest));
+                *proposed_desintations.entry(dest).or_insert(0) += 1;
+                break;
+            }
+        }
+    }
+
+    let mut have_move = false;
+    for (pos, dest) in proposed_moves.iter() {
+        if proposed_desintations[dest] == 1 {
+            map.remove(pos);
+            map.insert(*dest);
+            have_move = true;
+        }
+    }
+    trace::event("round", || {
+        let accepted = proposed_moves
+            .iter()
+            .filter(|(_, dest)| proposed_desintations[dest] == 1)
+            .collect::<Vec<_>>();
+        Object::new()
+            .field("ordering", ordering)
+            .field("proposed", &proposed_moves)
+            .field("accepted", accepted)
+    });
+
+    have_move
+}
+
+// Steps through the rounds until no elf moves
+struct Rounds {
+    map: HashSet<(i64, i64)>,
+    round: usize,
+}
+
+impl Simulation for Rounds {
+    fn step(&mut self) -> bool {
+        let moved = execute_round(&mut self.map, self.round % 4);
+        self.round += 1;
+        moved
+    }
+
+    fn render(&self) -> String {
+        format!("Round: {}\n{}", self.round, render_map(&self.map))
+    }
+}
+
+fn visualize(file_lines: &[String]) -> Box<dyn Simulation> {
+    Box::new(Rounds {
+        map: parse_lines(file_lines),
+        round: 0,
+    })
+}
+
+fn part1(elves: &HashSet<(i64, i64)>) -> String {
+    const PRINT_MAP: bool = false;
+
+    let mut map = elves.clone();
+    if PRINT_MAP {
+        print_map(&map);
+    }
+    asciicast::frame(|| render_map(&map));
+
+    // Elves move at most one tile per round, so this fits every round
+    let ((min_r, min_c), (max_r, max_c)) = map_bounds(&map);
+    let image_min = (min_r - 10, min_c - 10);
+    let image_max = (max_r + 10, max_c + 10);
+    image::frame("part1", || render_image(&map, image_min, image_max));
+
+    for round in 0..10 {
+        let ordering = round % 4;
+        execute_round(&mut map, ordering);
+
+        if PRINT_MAP {
+            println!("End of round {}", round + 1);
+            print_map(&map);
+        }
+        asciicast::frame(|| render_map(&map));
+        image::frame("part1", || render_image(&map, image_min, image_max));
+    }
+
+    let min_x = map.iter().map(|(r, _)| r).min().unwrap();
+    let max_x = map.iter().map(|(r, _)| r).max().unwrap();
+    let min_y = map.iter().map(|(_, c)| c).min().unwrap();
+    let max_y = map.iter().map(|(_, c)| c).max().unwrap();
+    let total_elves = map.len() as i64;
+    let total_empty = (max_x - min_x + 1) * (max_y - min_y + 1) - total_elves;
+    total_empty.to_string()
+}
+
+fn part2(elves: &HashSet<(i64, i64)>) -> String {
+    let mut map = elves.clone();
+
+    asciicast::frame(|| render_map(&map));
+
+    let mut round = 0;
+    while execute_round(&mut map, round % 4) {
+        round += 1;
+        asciicast::frame(|| render_map(&map));
+        image::frame("part2", || {
+            let (min, max) = map_bounds(&map);
+            render_image(&map, min, max)
+        });
+    }
+    round += 1;
+
+    image::snapshot("part2", || {
+        let (min, max) = map_bounds(&map);
+        render_image(&map, min, max)
+    });
+
+    round.to_string()
+}
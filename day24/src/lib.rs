@@ -0,0 +1,370 @@
+use std::cell::OnceCell;
+use std::collections::{BTreeMap, HashSet};
+use utility::asciicast;
+use utility::catalog::{Info, Technique};
+use utility::cycle::CycleDetector;
+use utility::dir::Dir4;
+use utility::graph::{self, astar_with};
+use utility::identify;
+use utility::json::Object;
+use utility::metrics;
+use utility::runner::{Day, Solution};
+use utility::span;
+use utility::trace;
+use utility::visualizer::Simulation;
+
+pub const DAY: Day = Day {
+    name: "day24",
+    input: "day24/input.txt",
+    //input: "day24/example-input.txt",
+    info: Info {
+        title: "Blizzard Basin",
+        implemented: [true, true],
+        techniques: &[Technique::AStar, Technique::CycleDetection],
+        examples: &["day24/example-input.txt"],
+    },
+    solution: &Solution {
+        parse: parse_valley,
+        part1,
+        part2,
+    },
+    is_input_line,
+    visualize: Some(visualize),
+    scaling: None,
+};
+
+// Trace events (--trace):
+//   "frontier": {"minute": m, "size": n} for each minute a search reached,
+//               in order, with the number of states it expanded at that
+//               minute. Part 2 runs three searches, so its minutes carry on
+//               from one to the next. Its first search is skipped if part 1
+//               already made that trip.
+//   "search": {"start": m, "minutes": n, "expanded": k} after each search's
+//             frontier events, with the minute it started at, how long the
+//             trip took and how many states the search expanded
+
+fn is_input_line(line: &str) -> bool {
+    line.starts_with('#') && identify::is_made_of(line, "#.<>^v")
+}
+
+#[derive(Debug, Clone)]
+struct Blizzards {
+    locations: Vec<(i64, i64, Dir4)>,
+    locations_lookup: HashSet<(i64, i64)>,
+    min: (i64, i64),
+    max: (i64, i64),
+}
+
+impl Blizzards {
+    fn from_lines(file_lines: &[String]) -> Self {
+        let mut blizzards = Vec::new();
+        let mut min = (0, 0);
+        let mut max = (0, 0);
+        for (r, line) in file_lines.iter().enumerate() {
+            for (c, ch) in line.chars().enumerate() {
+                match ch {
+                    '#' | '.' => {
+                        min.0 = min.0.min(r as i64);
+                        min.1 = min.1.min(c as i64);
+                        max.0 = max.0.max(r as i64);
+                        max.1 = max.1.max(c as i64);
+                    }
+                    '^' | 'v' | '>' | '<' => {
+                        let dir = Dir4::from_char(ch).unwrap();
+                        blizzards.push((r as i64, c as i64, dir));
+                    }
+                    _ => panic!("Unexpected character: {}", ch),
+                }
+            }
+        }
+        Self {
+            locations_lookup: blizzards.iter().map(|(r, c, _)| (*r, *c)).collect(),
+            locations: blizzards,
+            min,
+            max,
+        }
+    }
+
+    fn next(&self) -> Self {
+        let mut new_blizzards = Vec::with_capacity(self.locations.len());
+        for (r, c, dir) in self.locations.iter() {
+            let (r, c) = dir.step_row_col((*r, *c));
+            if r == self.min.0 {
+                new_blizzards.push((self.max.0 - 1, c, *dir));
+            } else if r == self.max.0 {
+                new_blizzards.push((self.min.0 + 1, c, *dir));
+            } else if c == self.min.1 {
+                new_blizzards.push((r, self.max.1 - 1, *dir));
+            } else if c == self.max.1 {
+                new_blizzards.push((r, self.min.1 + 1, *dir));
+            } else {
+                new_blizzards.push((r, c, *dir));
+            }
+        }
+        Self {
+            locations_lookup: new_blizzards.iter().map(|(r, c, _)| (*r, *c)).collect(),
+            locations: new_blizzards,
+            min: self.min,
+            max: self.max,
+        }
+    }
+}
+
+impl Blizzards {
+    // Every blizzard packed into one number, so comparing whole valleys is
+    // cheap enough to do every minute
+    fn key(&self) -> Vec<u64> {
+        self.locations
+            .iter()
+            .map(|&(r, c, dir)| (r as u64) << 32 | (c as u64) << 8 | dir.code() as u64)
+            .collect()
+    }
+}
+
+impl PartialEq for Blizzards {
+    fn eq(&self, other: &Self) -> bool {
+        self.locations == other.locations
+    }
+}
+
+impl Eq for Blizzards {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct State {
+    minute: usize,
+    position: (i64, i64),
+}
+
+impl State {
+    fn render(&self, blizzards: &Blizzards) -> String {
+        let entrance = (0, 1);
+        let exit = (blizzards.max.0, blizzards.max.1 - 1);
+
+        let mut output = format!("Minute: {}\n", self.minute);
+        for r in blizzards.min.0..=blizzards.max.0 {
+            for c in blizzards.min.1..=blizzards.max.1 {
+                let overlapping = blizzards
+                    .locations
+                    .iter()
+                    .filter(|(r2, c2, _)| r == *r2 && c == *c2)
+                    .collect::<Vec<_>>();
+
+                if self.position == (r, c) {
+                    output.push('E');
+                } else if entrance == (r, c) {
+                    output.push('e');
+                } else if exit == (r, c) {
+                    output.push('x');
+                } else if overlapping.len() > 1 {
+                    output += &overlapping.len().to_string();
+                } else if let Some((_, _, dir)) = overlapping.first() {
+                    output.push(dir.arrow());
+                } else if r == blizzards.min.0
+                    || r == blizzards.max.0
+                    || c == blizzards.min.1
+                    || c == blizzards.max.1
+                {
+                    output.push('#');
+                } else {
+                    output.push('.');
+                }
+            }
+            output.push('\n');
+        }
+        output
+    }
+
+    #[allow(dead_code)]
+    fn print(&self, blizzards: &Blizzards) {
+        print!("{}", self.render(blizzards));
+    }
+}
+
+// Steps the blizzards minute by minute until they're back where they started
+struct BlizzardSimulation {
+    initial: Blizzards,
+    blizzards: Blizzards,
+    state: State,
+}
+
+impl Simulation for BlizzardSimulation {
+    fn step(&mut self) -> bool {
+        let next = self.blizzards.next();
+        if next == self.initial {
+            return false;
+        }
+        self.blizzards = next;
+        self.state.minute += 1;
+        true
+    }
+
+    fn render(&self) -> String {
+        self.state.render(&self.blizzards)
+    }
+}
+
+fn visualize(file_lines: &[String]) -> Box<dyn Simulation> {
+    let blizzards = Blizzards::from_lines(file_lines);
+    Box::new(BlizzardSimulation {
+        initial: blizzards.clone(),
+        blizzards,
+        state: State {
+            minute: 0,
+            position: (0, 1),
+        },
+    })
+}
+
+fn all_possible_blizzards(initial_blizzards: Blizzards) -> Vec<Blizzards> {
+    let mut detector = CycleDetector::new();
+    detector.push(initial_blizzards.key());
+    let mut blizzard_state_per_minute = vec![initial_blizzards];
+    let cycle = loop {
+        let next_blizzard_state = blizzard_state_per_minute.last().unwrap().next();
+        if let Some(cycle) = detector.push(next_blizzard_state.key()) {
+            break cycle;
+        }
+        blizzard_state_per_minute.push(next_blizzard_state);
+    };
+    // Every blizzard moves in a loop, so they all end up back where they started
+    assert_eq!(cycle.start, 0);
+    blizzard_state_per_minute
+}
+
+// Returns the minute the exit is reached
+fn find_shortest_path(
+    initial_state: State,
+    exit: (i64, i64),
+    blizzard_state_per_minute: &[Blizzards],
+) -> Option<usize> {
+    let _span = span::enter("find_shortest_path");
+    let min = blizzard_state_per_minute[0].min;
+    let max = blizzard_state_per_minute[0].max;
+    let cycle_len = blizzard_state_per_minute.len();
+
+    // The blizzards repeat, so a node is the minute within their cycle and a
+    // position. Each minute we can wait or move, as long as no blizzard
+    // arrives there.
+    let valley = graph::from_fn(|&(cycle_minute, position): &(usize, (i64, i64))| {
+        let next_minute = (cycle_minute + 1) % cycle_len;
+        let next_blizzard_state = &blizzard_state_per_minute[next_minute];
+        let moves = Dir4::ALL.map(|dir| dir.step_row_col(position));
+        std::iter::once(position)
+            .chain(moves.into_iter().filter(|&(r, c)| {
+                (r, c) == exit || (r > min.0 && r < max.0 && c > min.1 && c < max.1)
+            }))
+            .filter(|position| !next_blizzard_state.locations_lookup.contains(position))
+            .map(|position| ((next_minute, position), 1))
+            .collect::<Vec<_>>()
+    });
+
+    // Each minute moves at most one step closer, so the Manhattan distance
+    // never overestimates the minutes left
+    let mut expanded_per_minute = BTreeMap::new();
+    let found = astar_with(
+        &valley,
+        (initial_state.minute % cycle_len, initial_state.position),
+        |&(_, position)| position == exit,
+        |&(_, (r, c))| (r - exit.0).abs() + (c - exit.1).abs(),
+        |_, minutes| *expanded_per_minute.entry(minutes).or_insert(0) += 1,
+    )?;
+    metrics::add("states_expanded", found.expanded as u64);
+    metrics::gauge_max("max_queue_len", found.max_open as i64);
+    for (&minutes, &size) in expanded_per_minute.iter() {
+        trace::event("frontier", || {
+            Object::new()
+                .field("minute", initial_state.minute + minutes as usize)
+                .field("size", size)
+        });
+    }
+    trace::event("search", || {
+        Object::new()
+            .field("start", initial_state.minute)
+            .field("minutes", found.cost)
+            .field("expanded", found.expanded)
+    });
+
+    // One frame per minute of the route taken
+    for (minutes, &(cycle_minute, position)) in found.path.iter().enumerate() {
+        asciicast::frame(|| {
+            let state = State {
+                minute: initial_state.minute + minutes,
+                position,
+            };
+            state.render(&blizzard_state_per_minute[cycle_minute])
+        });
+    }
+
+    Some(initial_state.minute + found.cost as usize)
+}
+
+struct Valley {
+    blizzard_state_per_minute: Vec<Blizzards>,
+    entrance: (i64, i64),
+    exit: (i64, i64),
+    // The first trip from the entrance to the exit, shared by both parts
+    first_trip: OnceCell<usize>,
+}
+
+impl Valley {
+    fn first_trip(&self) -> usize {
+        *self.first_trip.get_or_init(|| {
+            find_shortest_path(
+                State {
+                    minute: 0,
+                    position: self.entrance,
+                },
+                self.exit,
+                &self.blizzard_state_per_minute,
+            )
+            .unwrap()
+        })
+    }
+}
+
+fn parse_valley(file_lines: &[String]) -> Valley {
+    let blizzard_state_per_minute = span::time("all_possible_blizzards", || {
+        all_possible_blizzards(Blizzards::from_lines(file_lines))
+    });
+    let max = blizzard_state_per_minute[0].max;
+    Valley {
+        blizzard_state_per_minute,
+        entrance: (0, 1),
+        exit: (max.0, max.1 - 1),
+        first_trip: OnceCell::new(),
+    }
+}
+
+fn part1(valley: &Valley) -> String {
+    valley.first_trip().to_string()
+}
+
+fn part2(valley: &Valley) -> String {
+    let Valley {
+        blizzard_state_per_minute,
+        entrance,
+        exit,
+        ..
+    } = valley;
+    let shortest_path_a = valley.first_trip();
+
+    let shortest_path_b = find_shortest_path(
+        State {
+            minute: shortest_path_a + 1,
+            position: *exit,
+        },
+        *entrance,
+        blizzard_state_per_minute,
+    ).unwrap();
+
+    let shortest_path_c = find_shortest_path(
+        State {
+            minute: shortest_path_b + 1,
+            position: *entrance,
+        },
+        *exit,
+        blizzard_state_per_minute,
+    ).unwrap();
+
+    shortest_path_c.to_string()
+}
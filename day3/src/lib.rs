@@ -0,0 +1,74 @@
+use itertools::Itertools;
+use utility::catalog::{Info, Technique};
+use utility::runner::{Day, Solution};
+
+pub const DAY: Day = Day {
+    name: "day3",
+    input: "day3/input.txt",
+    //input: "day3/example_input.txt",
+    info: Info {
+        title: "Rucksack Reorganization",
+        implemented: [true, true],
+        techniques: &[Technique::Bitsets],
+        examples: &[],
+    },
+    solution: &Solution {
+        parse: parse_rucksacks,
+        part1,
+        part2,
+    },
+    is_input_line,
+    visualize: None,
+    scaling: None,
+};
+
+fn is_input_line(line: &str) -> bool {
+    !line.is_empty()
+        && line.len().is_multiple_of(2)
+        && line.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+fn priority(a: u8) -> u8 {
+    if a.is_ascii_lowercase() {
+        a - b'a' + 1
+    } else {
+        a - b'A' + 27
+    }
+}
+
+fn str_bits(s: &str) -> u64 {
+    s.bytes().fold(0u64, |acc, c| acc | 1u64 << priority(c))
+}
+
+// The items in each rucksack's two compartments, as bits
+fn parse_rucksacks(file_lines: &[String]) -> Vec<(u64, u64)> {
+    file_lines
+        .iter()
+        .map(|line| line.split_at(line.len() / 2))
+        .map(|(a, b)| (str_bits(a), str_bits(b)))
+        .collect()
+}
+
+fn part1(rucksacks: &[(u64, u64)]) -> String {
+    let priority_total: u64 = rucksacks
+        .iter()
+        .map(|(a, b)| (a & b).trailing_zeros() as u64)
+        .sum();
+
+    format!("{}", priority_total)
+}
+
+fn part2(rucksacks: &[(u64, u64)]) -> String {
+    let priority_total: u64 = rucksacks
+        .iter()
+        .chunks(3)
+        .into_iter()
+        .map(|chunk| {
+            chunk
+                .fold(u64::MAX, |acc, (a, b)| acc & (a | b))
+                .trailing_zeros() as u64
+        })
+        .sum();
+
+    format!("{}", priority_total)
+}
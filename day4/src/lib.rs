@@ -0,0 +1,74 @@
+use itertools::Itertools;
+use utility::catalog::{Info, Technique};
+use utility::identify;
+use utility::interval::Interval;
+use utility::runner::{Day, Solution};
+
+pub const DAY: Day = Day {
+    name: "day4",
+    input: "day4/input.txt",
+    //input: "day4/example-input.txt",
+    info: Info {
+        title: "Camp Cleanup",
+        implemented: [true, true],
+        techniques: &[Technique::IntervalOverlap],
+        examples: &["day4/example-input.txt"],
+    },
+    solution: &Solution {
+        parse: parse_ranges,
+        part1,
+        part2,
+    },
+    is_input_line,
+    visualize: None,
+    scaling: None,
+};
+
+// Two ranges like 2-4,6-8
+fn is_input_line(line: &str) -> bool {
+    let ranges = line.split(',').collect::<Vec<_>>();
+    ranges.len() == 2
+        && ranges
+            .iter()
+            .all(|range| identify::is_int_list(range, "-", 2))
+}
+
+fn parse_range(range: &str) -> Interval {
+    let (min, max) = range
+        .split('-')
+        .map(|s| s.parse().unwrap())
+        .collect_tuple()
+        .unwrap();
+    Interval::new(min, max)
+}
+
+fn parse_ranges(file_lines: &[String]) -> Vec<(Interval, Interval)> {
+    file_lines
+        .iter()
+        .filter_map(|line| {
+            line.split(',')
+                .map(parse_range)
+                .collect_tuple::<(Interval, Interval)>()
+        })
+        .collect()
+}
+
+fn part1(range_pairs: &[(Interval, Interval)]) -> String {
+    let num_fully_overlapped = range_pairs
+        .iter()
+        .filter(|ranges| {
+            ranges.0.contains_interval(&ranges.1) || ranges.1.contains_interval(&ranges.0)
+        })
+        .count();
+
+    format!("{}", num_fully_overlapped)
+}
+
+fn part2(range_pairs: &[(Interval, Interval)]) -> String {
+    let num_fully_overlapped = range_pairs
+        .iter()
+        .filter(|ranges| ranges.0.intersects(&ranges.1))
+        .count();
+
+    format!("{}", num_fully_overlapped)
+}
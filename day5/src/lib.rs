@@ -0,0 +1,157 @@
+use itertools::Itertools;
+use utility::catalog::{Info, Technique};
+use utility::identify;
+use utility::runner::{Day, Solution};
+
+pub const DAY: Day = Day {
+    name: "day5",
+    input: "day5/input.txt",
+    //input: "day5/example-input.txt",
+    info: Info {
+        title: "Supply Stacks",
+        implemented: [true, true],
+        techniques: &[Technique::Simulation],
+        examples: &["day5/example-input.txt"],
+    },
+    solution: &Solution {
+        parse: ParsedInput::from_lines,
+        part1,
+        part2,
+    },
+    is_input_line,
+    visualize: None,
+    scaling: None,
+};
+
+// The drawing of the stacks and their numbers, then the moves
+fn is_input_line(line: &str) -> bool {
+    let words = line.split(' ').collect::<Vec<_>>();
+    let is_move = matches!(words[..], ["move", count, "from", from, "to", to]
+        if [count, from, to].iter().all(|n| identify::is_int(n)));
+    line.is_empty()
+        || is_move
+        || (line.starts_with(' ') && identify::is_made_of(line, " 0123456789"))
+        || (line.contains('[') && identify::is_made_of(line, " []ABCDEFGHIJKLMNOPQRSTUVWXYZ"))
+}
+
+fn parse_stack_line(line: &str) -> Vec<u8> {
+    // Grab every 4th character starting at index 1
+    line.bytes().skip(1).step_by(4).collect()
+}
+
+#[derive(Debug, Clone)]
+struct Stack {
+    stack: Vec<u8>,
+}
+
+impl Stack {
+    fn new() -> Stack {
+        Stack { stack: Vec::new() }
+    }
+
+    fn push(&mut self, value: u8) {
+        self.stack.push(value);
+    }
+
+    fn take_multiple(&mut self, count: usize) -> Vec<u8> {
+        self.stack.split_off(self.stack.len() - count)
+    }
+
+    fn push_multiple(&mut self, values: &[u8]) {
+        self.stack.extend(values);
+    }
+
+    fn top(&self) -> char {
+        self.stack.last().map(|&c| c as char).unwrap_or(' ')
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Instruction {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+impl Instruction {
+    fn from_line(line: &str) -> Option<Instruction> {
+        let (count, from, to) = line
+            .split_ascii_whitespace()
+            .filter_map(|s| s.parse().ok())
+            .collect_tuple()?;
+
+        Some(Instruction {
+            count,
+            from: from - 1,
+            to: to - 1,
+        })
+    }
+
+    fn execute(&self, stacks: &mut [Stack], reverse: bool) {
+        let mut items = stacks[self.from].take_multiple(self.count);
+        if reverse {
+            items.reverse();
+        }
+        stacks[self.to].push_multiple(&items);
+    }
+}
+
+struct ParsedInput {
+    stacks: Vec<Stack>,
+    instructions: Vec<Instruction>,
+}
+
+impl ParsedInput {
+    fn from_lines(lines: &[String]) -> ParsedInput {
+        let split_index = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| s.is_empty())
+            .map(|(i, _)| i)
+            .next()
+            .unwrap();
+
+        let stack_lines = lines[..split_index - 1]
+            .iter()
+            .map(|s| parse_stack_line(s))
+            .collect_vec();
+        let num_stacks = stack_lines[0].len();
+        let mut stacks = vec![Stack::new(); num_stacks];
+        for stack_line in stack_lines.iter().rev() {
+            for (index, &item) in stack_line.iter().enumerate() {
+                if item != b' ' {
+                    stacks[index].push(item);
+                }
+            }
+        }
+
+        let instructions = lines[split_index + 1..]
+            .iter()
+            .filter_map(|s| Instruction::from_line(s))
+            .collect_vec();
+
+        ParsedInput {
+            stacks,
+            instructions,
+        }
+    }
+}
+
+fn do_part(parsed: &ParsedInput, reverse: bool) -> String {
+    // Execute the instructions on a copy of the stacks
+    let mut stacks = parsed.stacks.clone();
+    for instruction in parsed.instructions.iter() {
+        instruction.execute(&mut stacks, reverse);
+    }
+
+    // Create a string from the top of each stack
+    stacks.iter().map(|s| s.top()).collect()
+}
+
+fn part1(parsed: &ParsedInput) -> String {
+    do_part(parsed, true)
+}
+
+fn part2(parsed: &ParsedInput) -> String {
+    do_part(parsed, false)
+}
@@ -0,0 +1,52 @@
+use itertools::Itertools;
+use utility::catalog::{Info, Technique};
+use utility::runner::{Day, Solution};
+
+pub const DAY: Day = Day {
+    name: "day6",
+    input: "day6/input.txt",
+    //input: "day6/example-input.txt",
+    info: Info {
+        title: "Tuning Trouble",
+        implemented: [true, true],
+        techniques: &[Technique::SlidingWindow],
+        examples: &[],
+    },
+    solution: &Solution {
+        parse: parse_signal,
+        part1,
+        part2,
+    },
+    is_input_line,
+    visualize: None,
+    scaling: None,
+};
+
+fn is_input_line(line: &str) -> bool {
+    line.len() >= 14 && line.chars().all(|c| c.is_ascii_lowercase())
+}
+
+fn find_marker(line: &str, unique_len: usize) -> usize {
+    let line = line.bytes().enumerate().collect_vec();
+    for window in line.windows(unique_len) {
+        if window.iter().copied().map(|(_, c)| c).unique().count() == unique_len {
+            return window[unique_len - 1].0;
+        }
+    }
+
+    0
+}
+
+fn parse_signal(file_lines: &[String]) -> String {
+    file_lines[0].clone()
+}
+
+fn part1(signal: &str) -> String {
+    let marker_index = find_marker(signal, 4);
+    format!("{}", marker_index + 1)
+}
+
+fn part2(signal: &str) -> String {
+    let marker_index = find_marker(signal, 14);
+    format!("{}", marker_index + 1)
+}
@@ -0,0 +1,294 @@
+use itertools::Itertools;
+use std::collections::HashMap;
+use utility::catalog::{Info, Technique};
+use utility::dot;
+use utility::identify;
+use utility::runner::{Day, Solution};
+use utility::scaling::{Complexity, Random, Scaling};
+
+pub const DAY: Day = Day {
+    name: "day7",
+    input: "day7/input.txt",
+    //input: "day7/example-input.txt",
+    info: Info {
+        title: "No Space Left On Device",
+        implemented: [true, true],
+        techniques: &[Technique::TreeWalk],
+        examples: &["day7/example-input.txt"],
+    },
+    solution: &Solution {
+        parse: parse_folders,
+        part1,
+        part2,
+    },
+    is_input_line,
+    visualize: None,
+    scaling: Some(Scaling {
+        generate,
+        sizes: &[1000, 2000, 4000],
+        part1: Some(Complexity::Quadratic),
+        part2: Some(Complexity::Quadratic),
+    }),
+};
+
+// Commands and the listings they print
+fn is_input_line(line: &str) -> bool {
+    match line.split(' ').collect::<Vec<_>>()[..] {
+        ["$", "cd", _] | ["$", "ls"] | ["dir", _] => true,
+        [size, _] => identify::is_int(size),
+        _ => false,
+    }
+}
+
+// recursive_size isn't cached, so every folder sums up everything below it
+// again. Generates a random tree of n folders, each holding one file, with
+// about 50000000 bytes in total so part 2 has something to free.
+fn generate(n: usize) -> Vec<String> {
+    fn list_folder(lines: &mut Vec<String>, children: &[Vec<usize>], folder: usize, n: usize) {
+        lines.push("$ ls".to_string());
+        for child in children[folder].iter() {
+            lines.push(format!("dir d{}", child));
+        }
+        lines.push(format!("{} f.txt", 50000000 / n));
+        for &child in children[folder].iter() {
+            lines.push(format!("$ cd d{}", child));
+            list_folder(lines, children, child, n);
+            lines.push("$ cd ..".to_string());
+        }
+    }
+
+    let mut random = Random::new(7);
+    let mut children = vec![Vec::new(); n];
+    for folder in 1..n {
+        let parent = random.range(0, folder as i64 - 1) as usize;
+        children[parent].push(folder);
+    }
+    let mut lines = vec!["$ cd /".to_string()];
+    list_folder(&mut lines, &children, 0, n);
+    lines
+}
+
+#[derive(Debug)]
+enum LineContents {
+    None,
+    CD(String),
+    LS,
+    Dir(String),
+    File(String, usize),
+}
+
+fn parse_line(line: &str) -> LineContents {
+    if line.is_empty() {
+        LineContents::None
+    } else if line.as_bytes()[0] == b'$' {
+        parse_command(&line[2..])
+    } else {
+        parse_output(line)
+    }
+}
+
+fn parse_command(line: &str) -> LineContents {
+    let mut parts = line.split_whitespace();
+    let command = parts.next().unwrap();
+    match command {
+        "cd" => LineContents::CD(parts.next().unwrap().to_owned()),
+        "ls" => LineContents::LS,
+        _ => panic!("Unknown command: {}", command),
+    }
+}
+
+fn parse_output(line: &str) -> LineContents {
+    let mut parts = line.split_whitespace();
+    let size = parts.next().unwrap();
+    let name = parts.next().unwrap();
+    if size == "dir" {
+        LineContents::Dir(name.to_owned())
+    } else {
+        LineContents::File(name.to_owned(), size.parse().unwrap())
+    }
+}
+
+#[derive(Debug)]
+struct FileInfo {
+    name: String,
+    size: usize,
+}
+
+#[derive(Debug)]
+struct DirInfo {
+    name: String,
+    files: Vec<FileInfo>,
+    dirs: Vec<String>,
+}
+
+impl DirInfo {
+    fn new(name: String) -> DirInfo {
+        DirInfo {
+            name,
+            files: Vec::new(),
+            dirs: Vec::new(),
+        }
+    }
+
+    fn add_file(&mut self, file: FileInfo) {
+        if !self.files.iter().any(|f| f.name == file.name) {
+            self.files.push(file);
+        }
+    }
+
+    fn add_dir(&mut self, dir: &str) {
+        if !self.dirs.iter().any(|d| d == dir) {
+            self.dirs.push(dir.to_owned());
+        }
+    }
+
+    fn recursive_size(&self, folders: &HashMap<String, DirInfo>) -> usize {
+        self.files.iter().map(|file| file.size).sum::<usize>()
+            + self
+                .dirs
+                .iter()
+                .map(|dir| folders.get(dir).unwrap().recursive_size(folders))
+                .sum::<usize>()
+    }
+}
+
+fn parse_folders(file_lines: &[String]) -> HashMap::<String, DirInfo> {
+    let lines = file_lines.iter().map(|line| parse_line(line)).collect_vec();
+    let mut folders = HashMap::<String, DirInfo>::new();
+    // Push the root / folder
+    folders.insert("/".to_owned(), DirInfo::new("/".to_owned()));
+    let mut current_dir = "/".to_owned();
+    for line in lines {
+        match line {
+            LineContents::None => (),
+            LineContents::CD(dir) => {
+                if dir == ".." {
+                    // Go up a directory
+                    let path = current_dir.split('/').collect_vec();
+                    current_dir = path[..path.len() - 1].join("/");
+                } else if dir.starts_with('/') {
+                    // Go to a specific directory
+                    current_dir = dir.clone();
+                } else {
+                    // Enter a sub-folder
+                    if !current_dir.ends_with('/') {
+                        current_dir += "/";
+                    }
+                    current_dir += &dir;
+                }
+            }
+            LineContents::LS => {
+                // Do nothing
+            }
+            LineContents::Dir(dir_name) => {
+                let mut full_path = current_dir.clone();
+                if !full_path.ends_with('/') {
+                    full_path += "/";
+                }
+                full_path += &dir_name;
+
+                folders
+                    .get_mut(&current_dir)
+                    .unwrap()
+                    .add_dir(&full_path);
+                if !folders.contains_key(&full_path) {
+                    folders.insert(full_path.clone(), DirInfo::new(full_path.clone()));
+                }
+            }
+            LineContents::File(file, size) => {
+                folders
+                    .get_mut(&current_dir)
+                    .unwrap()
+                    .add_file(FileInfo { name: file, size });
+            }
+        }
+    }
+
+    folders
+}
+
+// Walks the tree from path so the graph comes out in a stable order
+fn add_folder_to_graph(
+    graph: &mut dot::Graph,
+    folders: &HashMap<String, DirInfo>,
+    path: &str,
+    highlighted: Option<&str>,
+) {
+    let dir = &folders[path];
+    let name = path
+        .rsplit('/')
+        .find(|name| !name.is_empty())
+        .unwrap_or("/");
+    let label = format!("{}\n{}", name, dir.recursive_size(folders));
+    let mut attributes = vec![("label", label.as_str())];
+    if highlighted == Some(path) {
+        attributes.extend([("style", "filled"), ("fillcolor", "red")]);
+    }
+    graph.node(path, &attributes);
+
+    for file in dir.files.iter() {
+        let file_path = format!("{}/{}", path.trim_end_matches('/'), file.name);
+        let label = format!("{}\n{}", file.name, file.size);
+        graph
+            .node(&file_path, &[("label", &label), ("shape", "note")])
+            .edge(path, &file_path, &[]);
+    }
+    for child in dir.dirs.iter() {
+        graph.edge(path, child, &[]);
+        add_folder_to_graph(graph, folders, child, highlighted);
+    }
+}
+
+// Folders are labelled with their total size
+fn folder_graph(folders: &HashMap<String, DirInfo>, highlighted: Option<&str>) -> dot::Graph {
+    let mut graph = dot::Graph::directed("day7");
+    graph
+        .attribute("rankdir", "LR")
+        .node_defaults(&[("shape", "folder")]);
+    add_folder_to_graph(&mut graph, folders, "/", highlighted);
+    graph
+}
+
+fn part1(folders: &HashMap<String, DirInfo>) -> String {
+    const SMALL_FOLDER_THRESHOLD: usize = 100000;
+
+    let folder_name_and_recursive_sizes = folders
+        .values()
+        .map(|dir| (dir.name.clone(), dir.recursive_size(folders)))
+        .collect_vec();
+
+    let size_of_small_folders = folder_name_and_recursive_sizes
+        .iter()
+        .filter(|(_, size)| *size <= SMALL_FOLDER_THRESHOLD)
+        .map(|(_, size)| size)
+        .sum::<usize>();
+
+    size_of_small_folders.to_string()
+}
+
+fn part2(folders: &HashMap<String, DirInfo>) -> String {
+    const TOTAL_SPACE: usize = 70000000;
+    const TOTAL_SPACE_NEEDED: usize = 30000000;
+
+    let space_used = folders.get("/").unwrap().recursive_size(folders);
+    let space_free = TOTAL_SPACE - space_used;
+    let space_needed = TOTAL_SPACE_NEEDED - space_free;
+
+    let potential_folders_to_delete = folders
+        .values()
+        .map(|dir| (dir.name.clone(), dir.recursive_size(folders)))
+        .filter(|(_, size)| *size >= space_needed)
+        .collect_vec();
+
+    let best_folder_to_delete = potential_folders_to_delete
+        .iter()
+        .min_by_key(|(_, size)| *size)
+        .unwrap();
+
+    dot::export(|highlight| {
+        let highlighted = highlight.then_some(best_folder_to_delete.0.as_str());
+        folder_graph(folders, highlighted)
+    });
+
+    format!("{}: {}", best_folder_to_delete.0, best_folder_to_delete.1)
+}
@@ -0,0 +1,159 @@
+use std::collections::HashSet;
+use utility::catalog::{Info, Technique};
+use utility::grid::Grid;
+use utility::identify;
+use utility::image::{self, Gradient, Image, Rgb};
+use utility::runner::{Day, Solution};
+
+pub const DAY: Day = Day {
+    name: "day8",
+    input: "day8/input.txt",
+    //input: "day8/example-input.txt",
+    info: Info {
+        title: "Treetop Tree House",
+        implemented: [true, true],
+        techniques: &[Technique::Simulation],
+        examples: &["day8/example-input.txt"],
+    },
+    solution: &Solution {
+        parse: parse_heights,
+        part1,
+        part2,
+    },
+    is_input_line,
+    visualize: None,
+    scaling: None,
+};
+
+fn is_input_line(line: &str) -> bool {
+    identify::is_made_of(line, "0123456789")
+}
+
+fn parse_heights(file_lines: &[String]) -> Grid<i32> {
+    Grid::parse(file_lines, |height| {
+        height.to_digit(10).map(|height| height as i32)
+    })
+    .expect("Unable to parse heights")
+}
+
+fn part1(heights: &Grid<i32>) -> String {
+    let mut visible_coords = HashSet::new();
+    let num_rows = heights.height();
+    let num_cols = heights.width();
+    for row in 0..num_rows {
+        let mut tallest: i32 = -1;
+        for col in 0..num_cols {
+            let h = heights[(col, row)];
+            if h > tallest {
+                visible_coords.insert((row, col));
+                tallest = h;
+            }
+        }
+
+        tallest = -1;
+        for col in (0..num_cols).rev() {
+            let h = heights[(col, row)];
+            if h > tallest {
+                visible_coords.insert((row, col));
+                tallest = h;
+            }
+        }
+    }
+
+    for col in 0..num_cols {
+        let mut tallest: i32 = -1;
+        for row in 0..num_rows {
+            let h = heights[(col, row)];
+            if h > tallest {
+                visible_coords.insert((row, col));
+                tallest = h;
+            }
+        }
+
+        tallest = -1;
+        for row in (0..num_rows).rev() {
+            let h = heights[(col, row)];
+            if h > tallest {
+                visible_coords.insert((row, col));
+                tallest = h;
+            }
+        }
+    }
+
+    image::snapshot("heights", || {
+        Image::from_grid(heights, &Gradient::terrain(0.0, 9.0))
+    });
+    image::snapshot("visible", || {
+        Image::from_fn(num_cols, num_rows, |col, row| {
+            if visible_coords.contains(&(row, col)) {
+                Rgb::GREEN
+            } else {
+                Rgb::BLACK
+            }
+        })
+    });
+
+    visible_coords.len().to_string()
+}
+
+fn part2(heights: &Grid<i32>) -> String {
+    let num_rows = heights.height();
+    let num_cols = heights.width();
+
+    let mut best_scenic_score = 0;
+    let mut scenic_scores = Grid::new(num_cols, num_rows, 0);
+    for col in 0..num_cols {
+        for row in 0..num_rows {
+            let mut cur_scenic_score = 1;
+            let cur_height = heights[(col, row)];
+
+            let mut dir_score = 0;
+            for other_row in (0..row).rev() {
+                dir_score += 1;
+                if heights[(col, other_row)] >= cur_height {
+                    break
+                }
+            }
+            cur_scenic_score *= dir_score;
+
+            dir_score = 0;
+            for other_row in (row + 1)..num_rows {
+                dir_score += 1;
+                if heights[(col, other_row)] >= cur_height {
+                    break
+                }
+            }
+            cur_scenic_score *= dir_score;
+
+            dir_score = 0;
+            for other_col in (0..col).rev() {
+                dir_score += 1;
+                if heights[(other_col, row)] >= cur_height {
+                    break
+                }
+            }
+            cur_scenic_score *= dir_score;
+
+            dir_score = 0;
+            for other_col in (col + 1)..num_cols {
+                dir_score += 1;
+                if heights[(other_col, row)] >= cur_height {
+                    break
+                }
+            }
+            cur_scenic_score *= dir_score;
+
+            best_scenic_score = std::cmp::max(best_scenic_score, cur_scenic_score);
+            scenic_scores[(col, row)] = cur_scenic_score;
+        }
+    }
+
+    image::snapshot("scenic", || {
+        Image::from_grid(
+            &scenic_scores,
+            &Gradient::heat(0.0, best_scenic_score as f64),
+        )
+    });
+
+    best_scenic_score.to_string()
+}
@@ -0,0 +1,173 @@
+use itertools::Itertools;
+use std::collections::HashSet;
+use utility::asciicast;
+use utility::catalog::{Info, Technique};
+use utility::dir::Dir4;
+use utility::identify;
+use utility::json::Object;
+use utility::point::Point2;
+use utility::runner::{Day, Solution};
+use utility::trace;
+
+pub const DAY: Day = Day {
+    name: "day9",
+    input: "day9/input.txt",
+    //input: "day9/example-input.txt",
+    //input: "day9/example-input2.txt",
+    info: Info {
+        title: "Rope Bridge",
+        implemented: [true, true],
+        techniques: &[Technique::Simulation],
+        examples: &["day9/example-input.txt", "day9/example-input2.txt"],
+    },
+    solution: &Solution {
+        parse: parse_moves,
+        part1,
+        part2,
+    },
+    is_input_line,
+    visualize: None,
+    scaling: None,
+};
+
+// Trace events (--trace):
+//   "knots": {"knots": [[x, y], ...]} after every step of the head, head first
+
+fn is_input_line(line: &str) -> bool {
+    match line.split_once(' ') {
+        Some((dir, dist)) => ["U", "D", "L", "R"].contains(&dir) && identify::is_int(dist),
+        None => false,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Move {
+    dir: Dir4,
+    dist: usize,
+}
+
+fn parse_moves(file_lines: &[String]) -> Vec<Move> {
+    let mut moves = Vec::new();
+    for line in file_lines {
+        let (dir, dist) = line.split(' ').collect_tuple().unwrap();
+        let dist: usize = dist.parse().unwrap();
+        let dir = Dir4::from_char(dir.chars().next().unwrap()).expect("Invalid direction");
+        moves.push(Move { dir, dist });
+    }
+    moves
+}
+
+// Tail moves one step towards the head, diagonally if needed, once they're
+// no longer touching
+fn adjust_tail(head_pos: Point2<isize>, tail_pos: Point2<isize>) -> Point2<isize> {
+    if head_pos.chebyshev(tail_pos) <= 1 {
+        tail_pos
+    } else {
+        tail_pos + (head_pos - tail_pos).signum()
+    }
+}
+
+fn render_positions_visited(
+    positions_visited: &HashSet<Point2<isize>>,
+    knot_positions: &[Point2<isize>],
+) -> String {
+    let mut min_x = 0;
+    let mut max_x = 0;
+    let mut min_y = 0;
+    let mut max_y = 0;
+    for pos in positions_visited.iter().chain(knot_positions) {
+        min_x = min_x.min(pos.x);
+        max_x = max_x.max(pos.x);
+        min_y = min_y.min(pos.y);
+        max_y = max_y.max(pos.y);
+    }
+
+    let mut output = String::new();
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            let pos = Point2::new(x, y);
+            let mut printed = false;
+            for (knot_index, &knot_position) in knot_positions.iter().enumerate() {
+                if pos == knot_position {
+                    if knot_index == 0 {
+                        output.push('H');
+                    } else {
+                        output += &knot_index.to_string();
+                    }
+                    printed = true;
+                    break;
+                }
+            }
+            if !printed {
+                if pos == Point2::origin() {
+                    output.push('s');
+                } else if positions_visited.contains(&pos) {
+                    output.push('#');
+                } else {
+                    output.push('.');
+                }
+            }
+        }
+        output.push('\n');
+    }
+    output
+}
+
+#[allow(dead_code)]
+fn print_positions_visited(
+    positions_visited: &HashSet<Point2<isize>>,
+    knot_positions: &[Point2<isize>],
+) {
+    println!(
+        "{}",
+        render_positions_visited(positions_visited, knot_positions)
+    );
+    println!();
+}
+
+fn trace_knots(knot_positions: &[Point2<isize>]) {
+    trace::event("knots", || Object::new().field("knots", knot_positions));
+}
+
+fn part1(moves: &[Move]) -> String {
+    let mut head_pos = Point2::origin();
+    let mut tail_pos = Point2::origin();
+    let mut positions_visited = HashSet::new();
+    positions_visited.insert(tail_pos);
+
+    for &m in moves.iter() {
+        for _ in 0..m.dist {
+            head_pos += m.dir.delta();
+            tail_pos = adjust_tail(head_pos, tail_pos);
+            positions_visited.insert(tail_pos);
+            trace_knots(&[head_pos, tail_pos]);
+        }
+        asciicast::frame(|| render_positions_visited(&positions_visited, &[head_pos, tail_pos]));
+    }
+
+    positions_visited.len().to_string()
+}
+
+fn part2(moves: &[Move]) -> String {
+    let mut knot_pos_arr = [Point2::origin(); 10];
+    let mut positions_visited = HashSet::new();
+    positions_visited.insert(knot_pos_arr[9]);
+
+    asciicast::frame(|| render_positions_visited(&positions_visited, &knot_pos_arr));
+
+    for &m in moves.iter() {
+        //println!("{:?}", m);
+        for _ in 0..m.dist {
+            knot_pos_arr[0] += m.dir.delta();
+
+            for i in 1..10 {
+                knot_pos_arr[i] = adjust_tail(knot_pos_arr[i - 1], knot_pos_arr[i]);
+            }
+            positions_visited.insert(knot_pos_arr[9]);
+            trace_knots(&knot_pos_arr);
+        }
+        asciicast::frame(|| render_positions_visited(&positions_visited, &knot_pos_arr));
+    }
+
+    positions_visited.len().to_string()
+}
@@ -0,0 +1,172 @@
+use std::cell::RefCell;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+
+use crate::json;
+
+// Clear the screen and move the cursor home before drawing each frame
+const CLEAR_SCREEN: &str = "\x1b[H\x1b[2J";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CastSettings {
+    // Playback speed of the recorded frames
+    pub fps: f64,
+    // Only keep one out of every this many frames
+    pub every: usize,
+    // Crop frames taller than this many rows
+    pub max_rows: Option<usize>,
+}
+
+impl Default for CastSettings {
+    fn default() -> Self {
+        Self {
+            fps: 10.0,
+            every: 1,
+            max_rows: None,
+        }
+    }
+}
+
+// Collects rendered frames and writes them out as an asciicast v2 file
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    settings: CastSettings,
+    frames: Vec<String>,
+    frames_seen: usize,
+}
+
+impl Recorder {
+    pub fn new(settings: CastSettings) -> Self {
+        Self {
+            settings,
+            frames: Vec::new(),
+            frames_seen: 0,
+        }
+    }
+
+    // Only calls render for the frames that are kept
+    pub fn frame(&mut self, render: impl FnOnce() -> String) {
+        let keep = self.frames_seen.is_multiple_of(self.settings.every.max(1));
+        self.frames_seen += 1;
+        if !keep {
+            return;
+        }
+
+        let frame = render();
+        let frame = match self.settings.max_rows {
+            Some(max_rows) => frame.lines().take(max_rows).collect::<Vec<_>>().join("\n"),
+            None => frame,
+        };
+        self.frames.push(frame);
+    }
+
+    pub fn frames(&self) -> &[String] {
+        &self.frames
+    }
+
+    pub fn write(&self, out: &mut dyn Write) -> io::Result<()> {
+        let width = self
+            .frames
+            .iter()
+            .flat_map(|frame| frame.lines())
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let height = self
+            .frames
+            .iter()
+            .map(|frame| frame.lines().count())
+            .max()
+            .unwrap_or(0);
+        writeln!(
+            out,
+            "{{\"version\": 2, \"width\": {}, \"height\": {}}}",
+            width.max(1),
+            height.max(1)
+        )?;
+
+        for (i, frame) in self.frames.iter().enumerate() {
+            let time = i as f64 / self.settings.fps;
+            let data = CLEAR_SCREEN.to_owned() + &frame.lines().collect::<Vec<_>>().join("\r\n");
+            writeln!(out, "[{:.6}, \"o\", {}]", time, json::string(&data))?;
+        }
+        Ok(())
+    }
+
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(path)?);
+        self.write(&mut out)?;
+        out.flush()
+    }
+}
+
+thread_local! {
+    static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+pub fn start_recording(settings: CastSettings) {
+    RECORDER.with(|recorder| *recorder.borrow_mut() = Some(Recorder::new(settings)));
+}
+
+pub fn stop_recording() -> Option<Recorder> {
+    RECORDER.with(|recorder| recorder.borrow_mut().take())
+}
+
+// Simulations call this with their rendering code. It does nothing unless a
+// recording has been started, so it's cheap to leave in.
+pub fn frame(render: impl FnOnce() -> String) {
+    RECORDER.with(|recorder| {
+        if let Some(recorder) = recorder.borrow_mut().as_mut() {
+            recorder.frame(render);
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_frame_skipping() {
+        let mut recorder = Recorder::new(CastSettings {
+            every: 3,
+            ..Default::default()
+        });
+        for i in 0..7 {
+            recorder.frame(|| i.to_string());
+        }
+        assert_eq!(recorder.frames(), ["0", "3", "6"]);
+    }
+
+    #[test]
+    fn test_write() {
+        let mut recorder = Recorder::new(CastSettings {
+            fps: 2.0,
+            every: 1,
+            max_rows: Some(2),
+        });
+        recorder.frame(|| "#..\n.#.\n..#\n".to_string());
+        recorder.frame(|| "##".to_string());
+
+        let mut out = Vec::new();
+        recorder.write(&mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines = out.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "{\"version\": 2, \"width\": 3, \"height\": 2}");
+        assert_eq!(
+            lines[1],
+            "[0.000000, \"o\", \"\\u001b[H\\u001b[2J#..\\r\\n.#.\"]"
+        );
+        assert_eq!(lines[2], "[0.500000, \"o\", \"\\u001b[H\\u001b[2J##\"]");
+    }
+
+    #[test]
+    fn test_not_recording() {
+        stop_recording();
+        frame(|| panic!("Should not render when not recording"));
+
+        start_recording(CastSettings::default());
+        frame(|| "x".to_string());
+        assert_eq!(stop_recording().unwrap().frames(), ["x"]);
+    }
+}
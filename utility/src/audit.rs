@@ -0,0 +1,183 @@
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
+use std::io::{self, Write};
+use std::rc::Rc;
+use std::thread;
+
+use crate::runner::{catch_panic, Outcome};
+use crate::trace::{self, Tracer};
+
+// How many times to run each part, and on how many threads at once.
+//
+// std's RandomState gives every new HashMap and HashSet different keys: each
+// thread starts from fresh random keys, and each map made on a thread after
+// that gets the next ones. So every run below hashes differently without the
+// days needing to know they're being audited.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditSettings {
+    // Rounds to run at each thread count
+    pub runs: usize,
+    // Each round runs the part this many times concurrently, once per thread
+    pub thread_counts: Vec<usize>,
+}
+
+impl Default for AuditSettings {
+    fn default() -> Self {
+        Self {
+            runs: 2,
+            thread_counts: vec![1, 2],
+        }
+    }
+}
+
+// Hashes the trace a run writes, so long traces can be compared cheaply.
+// DefaultHasher::new always uses the same keys, unlike RandomState.
+#[derive(Clone, Default)]
+struct TraceDigest(Rc<RefCell<DefaultHasher>>);
+
+impl Write for TraceDigest {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().write(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// What a single run of a part produced
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunResult {
+    pub outcome: Outcome,
+    // Digest of the --trace events the run wrote
+    pub trace: u64,
+}
+
+fn run_once(
+    part_name: &str,
+    part: &(dyn Fn(&[String]) -> String + Sync),
+    file_lines: &[String],
+) -> RunResult {
+    let digest = TraceDigest::default();
+    trace::start_trace(Tracer::new(Box::new(digest.clone())));
+    trace::set_part(part_name);
+    let outcome = match catch_panic(|| part(file_lines)) {
+        Ok(answer) => Outcome::Answer(answer),
+        Err(caught) => Outcome::Panicked(caught),
+    };
+    trace::stop_trace();
+
+    let trace = digest.0.borrow().finish();
+    RunResult { outcome, trace }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartAudit {
+    pub runs: Vec<RunResult>,
+}
+
+impl PartAudit {
+    fn distinct<T: PartialEq>(items: impl Iterator<Item = T>) -> Vec<T> {
+        let mut distinct = Vec::new();
+        for item in items {
+            if !distinct.contains(&item) {
+                distinct.push(item);
+            }
+        }
+        distinct
+    }
+
+    // Every different answer or panic, in the order they were first seen
+    pub fn distinct_outcomes(&self) -> Vec<&Outcome> {
+        Self::distinct(self.runs.iter().map(|run| &run.outcome))
+    }
+
+    pub fn distinct_traces(&self) -> usize {
+        Self::distinct(self.runs.iter().map(|run| run.trace)).len()
+    }
+
+    pub fn is_deterministic(&self) -> bool {
+        self.distinct_outcomes().len() <= 1 && self.distinct_traces() <= 1
+    }
+}
+
+// Runs a part over and over according to settings, collecting every result.
+// part should do all of its own parsing, so nothing is shared between runs.
+pub fn audit_part(
+    part_name: &str,
+    part: &(dyn Fn(&[String]) -> String + Sync),
+    file_lines: &[String],
+    settings: &AuditSettings,
+) -> PartAudit {
+    let mut runs = Vec::new();
+    for &thread_count in settings.thread_counts.iter() {
+        for _ in 0..settings.runs {
+            thread::scope(|scope| {
+                let handles = (0..thread_count.max(1))
+                    .map(|_| scope.spawn(|| run_once(part_name, part, file_lines)))
+                    .collect::<Vec<_>>();
+                runs.extend(handles.into_iter().map(|handle| handle.join().unwrap()));
+            });
+        }
+    }
+    PartAudit { runs }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::json::Object;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    fn stable_part(file_lines: &[String]) -> String {
+        trace::event("line", || {
+            Object::new().field("text", file_lines[0].as_str())
+        });
+        file_lines.len().to_string()
+    }
+
+    // Answers the same each time, but traces something different
+    fn unstable_trace_part(file_lines: &[String]) -> String {
+        let call = CALLS.fetch_add(1, Ordering::SeqCst);
+        trace::event("call", || Object::new().field("call", call));
+        file_lines.len().to_string()
+    }
+
+    fn unstable_answer_part(_file_lines: &[String]) -> String {
+        if CALLS.fetch_add(1, Ordering::SeqCst).is_multiple_of(2) {
+            "even".to_string()
+        } else {
+            panic!("odd")
+        }
+    }
+
+    #[test]
+    fn test_audit_part() {
+        let file_lines = vec!["a".to_string(), "b".to_string()];
+        let settings = AuditSettings {
+            runs: 2,
+            thread_counts: vec![1, 3],
+        };
+
+        let audit = audit_part("part1", &stable_part, &file_lines, &settings);
+        assert_eq!(audit.runs.len(), 8);
+        assert!(audit.is_deterministic());
+        assert_eq!(
+            audit.distinct_outcomes(),
+            [&Outcome::Answer("2".to_string())]
+        );
+
+        let audit = audit_part("part1", &unstable_trace_part, &file_lines, &settings);
+        assert_eq!(audit.distinct_outcomes().len(), 1);
+        assert_eq!(audit.distinct_traces(), 8);
+        assert!(!audit.is_deterministic());
+
+        let audit = audit_part("part2", &unstable_answer_part, &file_lines, &settings);
+        assert_eq!(audit.distinct_outcomes().len(), 2);
+        assert!(!audit.is_deterministic());
+    }
+}
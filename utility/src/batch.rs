@@ -0,0 +1,278 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+
+use crate::runner::{self, Day, DayReport, Outcome};
+
+const SIDECAR_EXTENSION: &str = "answer";
+
+// The answers an input should give, read from a sidecar file next to it with
+// the same name and an .answer extension. The first line is part 1's answer
+// and the rest is part 2's, so multi-line answers like day10's fit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub part1: String,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    pub fn parse(text: &str) -> Expected {
+        let (part1, part2) = match text.split_once('\n') {
+            Some((part1, part2)) => (part1, Some(part2)),
+            None => (text, None),
+        };
+        Expected {
+            part1: part1.trim().to_string(),
+            part2: part2
+                .map(|part2| part2.trim().to_string())
+                .filter(|part2| !part2.is_empty()),
+        }
+    }
+}
+
+pub fn sidecar_path(input: &Path) -> PathBuf {
+    input.with_extension(SIDECAR_EXTENSION)
+}
+
+pub fn read_expected(input: &Path) -> io::Result<Option<Expected>> {
+    match fs::read_to_string(sidecar_path(input)) {
+        Ok(text) => Ok(Some(Expected::parse(&text))),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    // No expected answer, or the part didn't give one
+    Unchecked,
+    Correct,
+    // Holds the expected answer
+    Wrong(String),
+}
+
+// Answers are compared with surrounding whitespace trimmed
+pub fn check(outcome: &Outcome, expected: Option<&str>) -> Check {
+    match (outcome, expected) {
+        (Outcome::Answer(answer), Some(expected)) => {
+            if answer.trim() == expected {
+                Check::Correct
+            } else {
+                Check::Wrong(expected.to_string())
+            }
+        }
+        _ => Check::Unchecked,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FileReport {
+    pub path: PathBuf,
+    pub report: DayReport,
+    // One check for each part
+    pub checks: Vec<Check>,
+}
+
+impl FileReport {
+    pub fn wrong_answers(&self) -> usize {
+        self.checks
+            .iter()
+            .filter(|check| matches!(check, Check::Wrong(_)))
+            .count()
+    }
+
+    pub fn passed(&self) -> bool {
+        self.report.succeeded() && self.wrong_answers() == 0
+    }
+}
+
+// Every file in dir except .answer sidecars, sorted by name
+pub fn input_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let is_sidecar = path
+            .extension()
+            .is_some_and(|extension| extension == SIDECAR_EXTENSION);
+        if path.is_file() && !is_sidecar {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+// Runs the day on one input, without printing its usual progress. Each input
+// gets its own thread, so thread locals such as memoization caches can't carry
+// answers over from the inputs before it.
+pub fn run_file(day: &Day, path: &Path) -> FileReport {
+    let report = thread::scope(|scope| {
+        scope
+            .spawn(|| runner::run_day(day, &path.to_string_lossy(), &mut io::sink()))
+            .join()
+            .unwrap()
+    });
+    let checks = match read_expected(path) {
+        Ok(expected) => {
+            let expected = [
+                expected.as_ref().map(|expected| expected.part1.as_str()),
+                expected
+                    .as_ref()
+                    .and_then(|expected| expected.part2.as_deref()),
+            ];
+            report.steps[1..]
+                .iter()
+                .zip(expected)
+                .map(|(step, expected)| check(&step.outcome, expected))
+                .collect()
+        }
+        Err(err) => {
+            let sidecar = sidecar_path(path);
+            vec![Check::Wrong(format!("unreadable {}: {}", sidecar.display(), err)); 2]
+        }
+    };
+    FileReport {
+        path: path.to_path_buf(),
+        report,
+        checks,
+    }
+}
+
+pub fn write_file_report(out: &mut dyn Write, file: &FileReport) -> io::Result<()> {
+    writeln!(out, "{}", file.path.display())?;
+    let checks = [Check::Unchecked].iter().chain(file.checks.iter());
+    for (step, check) in file.report.steps.iter().zip(checks) {
+        // Timings go first so multi-line answers don't split them off
+        write!(out, "    {}", step.name)?;
+        if step.outcome != Outcome::Skipped {
+            write!(out, " ({}us)", step.elapsed.as_micros())?;
+        }
+        write!(out, ": ")?;
+        match &step.outcome {
+            Outcome::Parsed => write!(out, "ok")?,
+            Outcome::Answer(answer) => write!(out, "{}", answer)?,
+            Outcome::Panicked(caught) => write!(out, "panicked: {}", caught)?,
+            Outcome::Skipped => write!(out, "skipped")?,
+        }
+        match check {
+            Check::Unchecked => writeln!(out)?,
+            Check::Correct => writeln!(out, " correct")?,
+            Check::Wrong(expected) => writeln!(out, " WRONG, expected {}", expected)?,
+        }
+    }
+    writeln!(out)
+}
+
+// Runs the day on every input in dir, writing each file's report as it
+// finishes
+pub fn run_batch(day: &Day, dir: &Path, out: &mut dyn Write) -> io::Result<Vec<FileReport>> {
+    let mut files = Vec::new();
+    for path in input_files(dir)? {
+        let file = run_file(day, &path);
+        write_file_report(out, &file)?;
+        out.flush()?;
+        files.push(file);
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::runner::{count_lines, lines_part, test_day, Solution};
+    use std::cell::RefCell;
+
+    thread_local! {
+        static CACHED_ANSWER: RefCell<Option<String>> = const { RefCell::new(None) };
+    }
+
+    fn small_input_part(num_lines: &usize) -> String {
+        assert!(*num_lines < 3, "Input too big");
+        "small".to_string()
+    }
+
+    // Caches its answer without knowing which input it was for, like day16's
+    // memoized searches
+    fn cached_part(num_lines: &usize) -> String {
+        CACHED_ANSWER.with(|cached| {
+            cached
+                .borrow_mut()
+                .get_or_insert_with(|| num_lines.to_string())
+                .clone()
+        })
+    }
+
+    #[test]
+    fn test_expected() {
+        let expected = Expected::parse("12\n\nline one\nline two\n");
+        assert_eq!(expected.part1, "12");
+        assert_eq!(expected.part2.as_deref(), Some("line one\nline two"));
+        assert_eq!(Expected::parse("12\n").part2, None);
+
+        let answer = Outcome::Answer("\nline one\nline two\n".to_string());
+        assert_eq!(check(&answer, expected.part2.as_deref()), Check::Correct);
+        assert_eq!(check(&answer, None), Check::Unchecked);
+        assert_eq!(check(&answer, Some("12")), Check::Wrong("12".to_string()));
+    }
+
+    #[test]
+    fn test_run_batch() {
+        let dir = std::env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("a.txt"), "1\n2\n").unwrap();
+        fs::write(dir.join("a.answer"), "2\nsmall\n").unwrap();
+        fs::write(dir.join("b.txt"), "1\n2\n3\n").unwrap();
+        fs::write(dir.join("b.answer"), "4\n").unwrap();
+        fs::write(dir.join("c"), "1\n").unwrap();
+
+        let day = test_day(&Solution {
+            parse: count_lines,
+            part1: lines_part,
+            part2: small_input_part,
+        });
+        let mut out = Vec::new();
+        let files = run_batch(&day, &dir, &mut out).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let names = files
+            .iter()
+            .map(|file| file.path.file_name().unwrap().to_string_lossy())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["a.txt", "b.txt", "c"]);
+        assert_eq!(files[0].checks, [Check::Correct, Check::Correct]);
+        assert!(files[0].passed());
+        assert_eq!(
+            files[1].checks,
+            [Check::Wrong("4".to_string()), Check::Unchecked]
+        );
+        assert!(!files[1].report.succeeded());
+        assert_eq!(files[2].checks, [Check::Unchecked, Check::Unchecked]);
+        assert!(files[2].passed());
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("us): 3 WRONG, expected 4\n"));
+        assert!(out.contains("us): panicked: Input too big"));
+    }
+
+    #[test]
+    fn test_run_batch_isolates_files() {
+        let dir = std::env::temp_dir().join(format!("aoc-batch-cached-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("a.txt"), "1\n").unwrap();
+        fs::write(dir.join("a.answer"), "1\n1\n").unwrap();
+        fs::write(dir.join("b.txt"), "1\n2\n").unwrap();
+        fs::write(dir.join("b.answer"), "2\n2\n").unwrap();
+
+        let day = test_day(&Solution {
+            parse: count_lines,
+            part1: cached_part,
+            part2: lines_part,
+        });
+        let files = run_batch(&day, &dir, &mut io::sink()).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(files.len(), 2);
+        assert!(files.iter().all(FileReport::passed));
+    }
+}
@@ -0,0 +1,265 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::batch;
+use crate::runner::{Day, DayReport};
+
+// Where aoc run --benchmark records each day's time, for aoc list to show
+pub const BENCHMARKS_PATH: &str = "benchmarks.txt";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Technique {
+    Sorting,
+    Simulation,
+    Bitsets,
+    IntervalOverlap,
+    SlidingWindow,
+    TreeWalk,
+    RecursiveParsing,
+    ModularArithmetic,
+    Dijkstra,
+    AStar,
+    BreadthFirstSearch,
+    FloodFill,
+    MemoizedDfs,
+    BranchAndBound,
+    CycleDetection,
+    DivideAndConquer,
+    BinarySearch,
+    BalancedBase,
+}
+
+impl Technique {
+    pub fn name(self) -> &'static str {
+        match self {
+            Technique::Sorting => "sorting",
+            Technique::Simulation => "simulation",
+            Technique::Bitsets => "bitsets",
+            Technique::IntervalOverlap => "interval overlap",
+            Technique::SlidingWindow => "sliding window",
+            Technique::TreeWalk => "tree walk",
+            Technique::RecursiveParsing => "recursive parsing",
+            Technique::ModularArithmetic => "modular arithmetic",
+            Technique::Dijkstra => "Dijkstra",
+            Technique::AStar => "A*",
+            Technique::BreadthFirstSearch => "BFS",
+            Technique::FloodFill => "flood fill",
+            Technique::MemoizedDfs => "memoized DFS",
+            Technique::BranchAndBound => "branch and bound",
+            Technique::CycleDetection => "cycle detection",
+            Technique::DivideAndConquer => "divide and conquer",
+            Technique::BinarySearch => "binary search",
+            Technique::BalancedBase => "balanced base",
+        }
+    }
+}
+
+// What aoc list shows about a day, declared next to its DAY
+#[derive(Debug, Clone, Copy)]
+pub struct Info {
+    pub title: &'static str,
+    // Whether each part gives a real answer rather than a placeholder
+    pub implemented: [bool; 2],
+    pub techniques: &'static [Technique],
+    // Example inputs, whose expected answers are recorded in .answer files
+    // next to them like aoc batch's
+    pub examples: &'static [&'static str],
+}
+
+impl Info {
+    // A day with both parts implemented and nothing else known about it
+    pub const fn new(title: &'static str) -> Self {
+        Self {
+            title,
+            implemented: [true, true],
+            techniques: &[],
+            examples: &[],
+        }
+    }
+
+    // Whether every example has its answers recorded, or None if there are
+    // no examples
+    pub fn examples_recorded(&self) -> Option<bool> {
+        if self.examples.is_empty() {
+            return None;
+        }
+        Some(
+            self.examples
+                .iter()
+                .all(|example| batch::sidecar_path(Path::new(example)).is_file()),
+        )
+    }
+}
+
+// Each day's total time from the last run that recorded it, one "day time"
+// line per day
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Benchmarks {
+    times: BTreeMap<String, Duration>,
+}
+
+impl Benchmarks {
+    pub fn parse(text: &str) -> Benchmarks {
+        let times = text
+            .lines()
+            .filter_map(|line| {
+                let (day, time) = line.split_once(' ')?;
+                let micros = time.trim().strip_suffix("us")?.parse().ok()?;
+                Some((day.to_string(), Duration::from_micros(micros)))
+            })
+            .collect();
+        Benchmarks { times }
+    }
+
+    // Missing files have no benchmarks yet
+    pub fn read(path: &str) -> io::Result<Benchmarks> {
+        match fs::read_to_string(path) {
+            Ok(text) => Ok(Benchmarks::parse(&text)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Benchmarks::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn write(&self, path: &str) -> io::Result<()> {
+        let text = self
+            .times
+            .iter()
+            .map(|(day, time)| format!("{} {}us\n", day, time.as_micros()))
+            .collect::<String>();
+        fs::write(path, text)
+    }
+
+    pub fn get(&self, day: &str) -> Option<Duration> {
+        self.times.get(day).copied()
+    }
+
+    // Records the total time of every day that ran without failing
+    pub fn record(&mut self, reports: &[DayReport]) {
+        for report in reports.iter().filter(|report| report.succeeded()) {
+            let total = report.steps.iter().map(|step| step.elapsed).sum();
+            self.times.insert(report.name.to_string(), total);
+        }
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    if duration >= Duration::from_secs(1) {
+        format!("{:.1}s", duration.as_secs_f64())
+    } else if duration >= Duration::from_millis(1) {
+        format!("{:.1}ms", duration.as_secs_f64() * 1000.0)
+    } else {
+        format!("{}us", duration.as_micros())
+    }
+}
+
+// One line per day: its title, which parts are implemented, whether example
+// answers are recorded, its last benchmark and the techniques it uses
+pub fn write_catalog(
+    out: &mut dyn Write,
+    days: &[&Day],
+    benchmarks: &Benchmarks,
+) -> io::Result<()> {
+    writeln!(
+        out,
+        "{:<6} {:<26} {:<6} {:<9} {:<10} Techniques",
+        "Day", "Title", "Parts", "Examples", "Benchmark"
+    )?;
+    for day in days.iter() {
+        let parts = day
+            .info
+            .implemented
+            .iter()
+            .enumerate()
+            .map(|(index, implemented)| {
+                if *implemented {
+                    (index + 1).to_string()
+                } else {
+                    "-".to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        let examples = match day.info.examples_recorded() {
+            Some(true) => "recorded",
+            Some(false) => "missing",
+            None => "-",
+        };
+        let benchmark = benchmarks
+            .get(day.name)
+            .map(format_duration)
+            .unwrap_or_else(|| "-".to_string());
+        let techniques = day
+            .info
+            .techniques
+            .iter()
+            .map(|technique| technique.name())
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(
+            out,
+            "{:<6} {:<26} {:<6} {:<9} {:<10} {}",
+            day.name, day.info.title, parts, examples, benchmark, techniques
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::metrics::Metrics;
+    use crate::runner::{count_lines, lines_part, test_day, Outcome, Solution, StepReport};
+
+    fn step(name: &'static str, micros: u64) -> StepReport {
+        StepReport {
+            name,
+            outcome: Outcome::Parsed,
+            elapsed: Duration::from_micros(micros),
+            spans: Vec::new(),
+            metrics: Metrics::new(),
+        }
+    }
+
+    #[test]
+    fn test_benchmarks() {
+        let mut benchmarks = Benchmarks::parse("day1 1500us\nbad line\nday2 xus\n");
+        assert_eq!(benchmarks.get("day1"), Some(Duration::from_micros(1500)));
+        assert_eq!(benchmarks.get("day2"), None);
+
+        let report = DayReport {
+            name: "day2",
+            steps: vec![step("Parsing", 10), step("Part 1", 20), step("Part 2", 30)],
+        };
+        benchmarks.record(&[report]);
+        assert_eq!(benchmarks.get("day2"), Some(Duration::from_micros(60)));
+        assert_eq!(Benchmarks::parse("day1 1500us\nday2 60us\n"), benchmarks);
+    }
+
+    #[test]
+    fn test_write_catalog() {
+        let day = Day {
+            name: "day25",
+            info: Info {
+                title: "Full of Hot Air",
+                implemented: [true, false],
+                techniques: &[Technique::BalancedBase, Technique::Sorting],
+                examples: &[],
+            },
+            ..test_day(&Solution {
+                parse: count_lines,
+                part1: lines_part,
+                part2: lines_part,
+            })
+        };
+        let benchmarks = Benchmarks::parse("day25 2500us\n");
+        let mut out = Vec::new();
+        write_catalog(&mut out, &[&day], &benchmarks).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let line = out.lines().nth(1).unwrap();
+        assert!(line.starts_with("day25  Full of Hot Air"));
+        assert!(line.contains(" 1 -    -         2.5ms      balanced base, sorting"));
+    }
+}
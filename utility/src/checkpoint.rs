@@ -0,0 +1,316 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::time::{Duration, Instant};
+
+// State a search can save to a checkpoint and pick up again later. It's
+// written as a flat list of integers, so implementations just push their
+// fields in order and read them back in the same order.
+pub trait Checkpoint: Sized {
+    fn save(&self, words: &mut Vec<i64>);
+    fn load(words: &mut dyn Iterator<Item = i64>) -> Option<Self>;
+}
+
+impl Checkpoint for i64 {
+    fn save(&self, words: &mut Vec<i64>) {
+        words.push(*self);
+    }
+
+    fn load(words: &mut dyn Iterator<Item = i64>) -> Option<Self> {
+        words.next()
+    }
+}
+
+impl Checkpoint for usize {
+    fn save(&self, words: &mut Vec<i64>) {
+        words.push(*self as i64);
+    }
+
+    fn load(words: &mut dyn Iterator<Item = i64>) -> Option<Self> {
+        usize::try_from(words.next()?).ok()
+    }
+}
+
+impl Checkpoint for u64 {
+    fn save(&self, words: &mut Vec<i64>) {
+        words.push(*self as i64);
+    }
+
+    fn load(words: &mut dyn Iterator<Item = i64>) -> Option<Self> {
+        Some(words.next()? as u64)
+    }
+}
+
+// Saved as its length followed by each item
+impl<T: Checkpoint> Checkpoint for Vec<T> {
+    fn save(&self, words: &mut Vec<i64>) {
+        self.len().save(words);
+        for item in self.iter() {
+            item.save(words);
+        }
+    }
+
+    fn load(words: &mut dyn Iterator<Item = i64>) -> Option<Self> {
+        let len = usize::load(words)?;
+        (0..len).map(|_| T::load(words)).collect()
+    }
+}
+
+macro_rules! impl_checkpoint_tuple {
+    ($($t:ident),*) => {
+        impl<$($t: Checkpoint),*> Checkpoint for ($($t,)*) {
+            #[allow(non_snake_case)]
+            fn save(&self, words: &mut Vec<i64>) {
+                let ($($t,)*) = self;
+                $($t.save(words);)*
+            }
+
+            fn load(words: &mut dyn Iterator<Item = i64>) -> Option<Self> {
+                Some(($($t::load(words)?,)*))
+            }
+        }
+    };
+}
+
+impl_checkpoint_tuple!(A, B);
+impl_checkpoint_tuple!(A, B, C);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckpointSettings {
+    pub path: String,
+    // How often a running search saves its state
+    pub every: Duration,
+    // Whether to continue from the searches already saved in path
+    pub resume: bool,
+}
+
+impl Default for CheckpointSettings {
+    fn default() -> Self {
+        Self {
+            path: String::new(),
+            every: Duration::from_secs(60),
+            resume: false,
+        }
+    }
+}
+
+// Holds every search's latest saved state and writes them all to one file.
+// The file's first line names the run, so a checkpoint isn't resumed against
+// a different day or input. Each line after that is a search's key followed
+// by its saved words.
+pub struct Checkpointer {
+    settings: CheckpointSettings,
+    run: String,
+    part: String,
+    saved: BTreeMap<String, Vec<i64>>,
+    // States read from the file that haven't been resumed yet
+    to_resume: BTreeMap<String, Vec<i64>>,
+    last_saved: Instant,
+    saves: usize,
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+impl Checkpointer {
+    // Reads the saved states back in when resuming. run names the day and
+    // input being run.
+    pub fn new(settings: CheckpointSettings, run: &str) -> io::Result<Self> {
+        let saved = if settings.resume {
+            Self::read(&settings.path, run)?
+        } else {
+            BTreeMap::new()
+        };
+        Ok(Self {
+            settings,
+            run: run.to_string(),
+            part: String::new(),
+            to_resume: saved.clone(),
+            saved,
+            last_saved: Instant::now(),
+            saves: 0,
+        })
+    }
+
+    fn read(path: &str, run: &str) -> io::Result<BTreeMap<String, Vec<i64>>> {
+        let text = fs::read_to_string(path)?;
+        let mut lines = text.lines();
+        let saved_run = lines.next().unwrap_or_default();
+        if saved_run != run {
+            return Err(invalid_data(format!(
+                "checkpoint is for {:?}, not {:?}",
+                saved_run, run
+            )));
+        }
+
+        let mut saved = BTreeMap::new();
+        for line in lines {
+            let mut words = line.split_whitespace();
+            let Some(key) = words.next() else {
+                continue;
+            };
+            let words = words
+                .map(|word| word.parse::<i64>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| invalid_data(format!("bad checkpoint line for {}", key)))?;
+            saved.insert(key.to_string(), words);
+        }
+        Ok(saved)
+    }
+
+    // Writes to a temporary file first so an interrupted save doesn't lose
+    // the previous checkpoint
+    fn write(&self) -> io::Result<()> {
+        let mut text = format!("{}\n", self.run);
+        for (key, words) in self.saved.iter() {
+            text.push_str(key);
+            for word in words.iter() {
+                text.push_str(&format!(" {}", word));
+            }
+            text.push('\n');
+        }
+        let temp_path = format!("{}.tmp", self.settings.path);
+        fs::write(&temp_path, text)?;
+        fs::rename(&temp_path, &self.settings.path)
+    }
+
+    // Keys are scoped to the part, so both parts can run the same search
+    pub fn set_part(&mut self, part: &str) {
+        self.part = part.to_string();
+    }
+
+    fn full_key(&self, key: &str) -> String {
+        format!("{}/{}", self.part, key)
+    }
+
+    pub fn resume<T: Checkpoint>(&mut self, key: &str) -> Option<T> {
+        let words = self.to_resume.remove(&self.full_key(key))?;
+        T::load(&mut words.into_iter())
+    }
+
+    pub fn is_due(&self) -> bool {
+        self.last_saved.elapsed() >= self.settings.every
+    }
+
+    pub fn save<T: Checkpoint>(&mut self, key: &str, state: &T) -> io::Result<()> {
+        let mut words = Vec::new();
+        state.save(&mut words);
+        self.saved.insert(self.full_key(key), words);
+        self.write()?;
+        self.last_saved = Instant::now();
+        self.saves += 1;
+        Ok(())
+    }
+
+    pub fn saves(&self) -> usize {
+        self.saves
+    }
+}
+
+thread_local! {
+    static CHECKPOINTER: RefCell<Option<Checkpointer>> = const { RefCell::new(None) };
+}
+
+pub fn start_checkpointing(checkpointer: Checkpointer) {
+    CHECKPOINTER.with(|current| *current.borrow_mut() = Some(checkpointer));
+}
+
+pub fn stop_checkpointing() -> Option<Checkpointer> {
+    CHECKPOINTER.with(|current| current.borrow_mut().take())
+}
+
+pub fn set_part(part: &str) {
+    CHECKPOINTER.with(|current| {
+        if let Some(checkpointer) = current.borrow_mut().as_mut() {
+            checkpointer.set_part(part);
+        }
+    });
+}
+
+// Searches call these with a key naming the search within the part. They do
+// nothing unless checkpointing has been started, so they're cheap to leave in.
+
+// The state saved for key by an earlier run, when resuming
+pub fn resume<T: Checkpoint>(key: &str) -> Option<T> {
+    CHECKPOINTER.with(|current| current.borrow_mut().as_mut()?.resume(key))
+}
+
+// Saves the state if it's been long enough since the last save
+pub fn periodic<T: Checkpoint>(key: &str, state: impl FnOnce() -> T) {
+    CHECKPOINTER.with(|current| {
+        if let Some(checkpointer) = current.borrow_mut().as_mut() {
+            if checkpointer.is_due() {
+                checkpointer
+                    .save(key, &state())
+                    .expect("Unable to write checkpoint file");
+            }
+        }
+    });
+}
+
+// Saves the state now, such as a finished search's result
+pub fn save<T: Checkpoint>(key: &str, state: impl FnOnce() -> T) {
+    CHECKPOINTER.with(|current| {
+        if let Some(checkpointer) = current.borrow_mut().as_mut() {
+            checkpointer
+                .save(key, &state())
+                .expect("Unable to write checkpoint file");
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_checkpoint_words() {
+        let state = (7i64, vec![(1usize, 2u64), (3, 4)]);
+        let mut words = Vec::new();
+        state.save(&mut words);
+        assert_eq!(words, [7, 2, 1, 2, 3, 4]);
+        assert_eq!(
+            <(i64, Vec<(usize, u64)>)>::load(&mut words.into_iter()),
+            Some(state)
+        );
+        assert_eq!(<(i64, i64)>::load(&mut [1].into_iter()), None);
+    }
+
+    #[test]
+    fn test_resume() {
+        let path = std::env::temp_dir()
+            .join(format!("aoc-checkpoint-{}.txt", std::process::id()))
+            .to_string_lossy()
+            .into_owned();
+        let settings = CheckpointSettings {
+            path: path.clone(),
+            every: Duration::ZERO,
+            resume: false,
+        };
+
+        start_checkpointing(Checkpointer::new(settings.clone(), "test input.txt").unwrap());
+        set_part("part1");
+        assert_eq!(resume::<i64>("search"), None);
+        periodic("search", || (5i64, vec![1i64, 2]));
+        set_part("part2");
+        save("search", || 9i64);
+        assert_eq!(stop_checkpointing().unwrap().saves(), 2);
+
+        let resuming = CheckpointSettings {
+            resume: true,
+            ..settings
+        };
+        assert!(Checkpointer::new(resuming.clone(), "other input.txt").is_err());
+        start_checkpointing(Checkpointer::new(resuming, "test input.txt").unwrap());
+        set_part("part1");
+        assert_eq!(resume("search"), Some((5i64, vec![1i64, 2])));
+        // Each saved state is only resumed once
+        assert_eq!(resume::<(i64, Vec<i64>)>("search"), None);
+        set_part("part2");
+        assert_eq!(resume("search"), Some(9i64));
+        stop_checkpointing();
+        fs::remove_file(&path).unwrap();
+    }
+}
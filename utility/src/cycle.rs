@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Where a sequence of states starts repeating: the state after start steps
+// comes back every period steps from then on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+// Remembers the step each state was first seen at. Keys only need to capture
+// enough of a state to decide everything that follows from it.
+#[derive(Debug, Clone)]
+pub struct CycleDetector<K> {
+    seen: HashMap<K, usize>,
+}
+
+impl<K: Hash + Eq> Default for CycleDetector<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Hash + Eq> CycleDetector<K> {
+    pub fn new() -> Self {
+        Self {
+            seen: HashMap::new(),
+        }
+    }
+
+    // Number of states pushed so far
+    pub fn steps(&self) -> usize {
+        self.seen.len()
+    }
+
+    // Records the state after the next step, starting with the state after
+    // step 0. Returns the cycle once a state repeats.
+    pub fn push(&mut self, key: K) -> Option<Cycle> {
+        let step = self.seen.len();
+        match self.seen.get(&key) {
+            Some(&start) => Some(Cycle {
+                start,
+                period: step - start,
+            }),
+            None => {
+                self.seen.insert(key, step);
+                None
+            }
+        }
+    }
+}
+
+// The cycle in a sequence of states, if it repeats before running out
+pub fn find_cycle<K: Hash + Eq>(states: impl IntoIterator<Item = K>) -> Option<Cycle> {
+    let mut detector = CycleDetector::new();
+    states.into_iter().find_map(|key| detector.push(key))
+}
+
+// Extends a running total past where it was simulated. totals[i] is the total
+// after i steps, and must run at least one full period past the cycle's start.
+// Each period adds the same amount once the cycle has started.
+pub fn extrapolate(totals: &[i64], cycle: Cycle, step: u64) -> i64 {
+    if let Some(&total) = totals.get(step as usize) {
+        return total;
+    }
+    let Cycle { start, period } = cycle;
+    assert!(
+        totals.len() > start + period,
+        "Need totals up to step {} to extrapolate",
+        start + period
+    );
+    let per_period = totals[start + period] - totals[start];
+    let periods = (step - start as u64) / period as u64;
+    let leftover = ((step - start as u64) % period as u64) as usize;
+    totals[start + leftover] + per_period * periods as i64
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find_cycle() {
+        // 3, 1, 4 and then 2, 7, 5 forever
+        let states = [3, 1, 4, 2, 7, 5, 2, 7, 5, 2];
+        assert_eq!(
+            find_cycle(states),
+            Some(Cycle {
+                start: 3,
+                period: 3
+            })
+        );
+        assert_eq!(find_cycle([1, 2, 3]), None);
+
+        let mut detector = CycleDetector::new();
+        assert_eq!(detector.push("a"), None);
+        assert_eq!(
+            detector.push("a"),
+            Some(Cycle {
+                start: 0,
+                period: 1
+            })
+        );
+        assert_eq!(detector.steps(), 1);
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        // Adds 1 for 2 steps, then 2 and 3 over and over
+        let deltas = [1, 1, 2, 3, 2, 3];
+        let totals = std::iter::once(0)
+            .chain(deltas.iter().scan(0, |total, delta| {
+                *total += delta;
+                Some(*total)
+            }))
+            .collect::<Vec<i64>>();
+        let cycle = Cycle {
+            start: 2,
+            period: 2,
+        };
+        assert_eq!(extrapolate(&totals, cycle, 5), 9);
+        assert_eq!(extrapolate(&totals, cycle, 7), 14);
+        assert_eq!(extrapolate(&totals, cycle, 8), 17);
+        assert_eq!(
+            extrapolate(&totals, cycle, 1_000_000_000_000),
+            2 + 5 * 499_999_999_999
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use crate::point::{Coord, Point2};
+
+// One of the four orthogonal directions on a grid where y counts down. They're
+// numbered clockwise from Right, which is the order puzzles use for facing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir4 {
+    Right,
+    Down,
+    Left,
+    Up,
+}
+
+// Dir4 plus the diagonals, numbered clockwise from Right like Dir4
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir8 {
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+    Up,
+    UpRight,
+}
+
+impl Dir4 {
+    // Every direction, in code order
+    pub const ALL: [Dir4; 4] = [Dir4::Right, Dir4::Down, Dir4::Left, Dir4::Up];
+
+    pub fn code(self) -> u8 {
+        self as u8
+    }
+
+    pub fn from_code(code: u8) -> Option<Self> {
+        Self::ALL.get(code as usize).copied()
+    }
+
+    // Accepts U/D/L/R, ^v<> and N/S/E/W, with north being up
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'R' | '>' | 'E' => Some(Dir4::Right),
+            'D' | 'v' | 'S' => Some(Dir4::Down),
+            'L' | '<' | 'W' => Some(Dir4::Left),
+            'U' | '^' | 'N' => Some(Dir4::Up),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Dir4::Right => '>',
+            Dir4::Down => 'v',
+            Dir4::Left => '<',
+            Dir4::Up => '^',
+        }
+    }
+
+    // Turns clockwise by this many quarter turns, or anticlockwise if negative
+    pub fn rotate(self, quarter_turns: i32) -> Self {
+        Self::ALL[(self.code() as i32 + quarter_turns).rem_euclid(4) as usize]
+    }
+
+    pub fn turn_right(self) -> Self {
+        self.rotate(1)
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.rotate(-1)
+    }
+
+    pub fn reverse(self) -> Self {
+        self.rotate(2)
+    }
+
+    // The step one move in this direction takes
+    pub fn delta<T: Coord>(self) -> Point2<T> {
+        Dir8::from(self).delta()
+    }
+
+    pub fn step_row_col<T: Coord>(self, position: (T, T)) -> (T, T) {
+        Dir8::from(self).step_row_col(position)
+    }
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+        Dir8::Up,
+        Dir8::UpRight,
+    ];
+
+    pub fn code(self) -> u8 {
+        self as u8
+    }
+
+    pub fn from_code(code: u8) -> Option<Self> {
+        Self::ALL.get(code as usize).copied()
+    }
+
+    // Accepts compass points such as N, NE and E, with north being up
+    pub fn from_compass(name: &str) -> Option<Self> {
+        match name {
+            "E" => Some(Dir8::Right),
+            "SE" => Some(Dir8::DownRight),
+            "S" => Some(Dir8::Down),
+            "SW" => Some(Dir8::DownLeft),
+            "W" => Some(Dir8::Left),
+            "NW" => Some(Dir8::UpLeft),
+            "N" => Some(Dir8::Up),
+            "NE" => Some(Dir8::UpRight),
+            _ => None,
+        }
+    }
+
+    // Turns clockwise by this many eighth turns, or anticlockwise if negative
+    pub fn rotate(self, eighth_turns: i32) -> Self {
+        Self::ALL[(self.code() as i32 + eighth_turns).rem_euclid(8) as usize]
+    }
+
+    // Turns a quarter turn, like Dir4's, so orthogonal directions stay
+    // orthogonal
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.rotate(-2)
+    }
+
+    pub fn reverse(self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn delta<T: Coord>(self) -> Point2<T> {
+        let (zero, one) = (T::ZERO, T::ONE);
+        match self {
+            Dir8::Right => Point2::new(one, zero),
+            Dir8::DownRight => Point2::new(one, one),
+            Dir8::Down => Point2::new(zero, one),
+            Dir8::DownLeft => Point2::new(-one, one),
+            Dir8::Left => Point2::new(-one, zero),
+            Dir8::UpLeft => Point2::new(-one, -one),
+            Dir8::Up => Point2::new(zero, -one),
+            Dir8::UpRight => Point2::new(one, -one),
+        }
+    }
+
+    // Moves a (row, column) position one step, for grids stored row first.
+    // Rows count down the grid like y does, so they move by the delta's y.
+    pub fn step_row_col<T: Coord>(self, (row, col): (T, T)) -> (T, T) {
+        let delta = self.delta::<T>();
+        (row + delta.y, col + delta.x)
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::ALL[dir.code() as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dir4() {
+        assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+        assert_eq!(Dir4::Right.turn_left(), Dir4::Up);
+        assert_eq!(Dir4::Left.reverse(), Dir4::Right);
+        assert_eq!(Dir4::Down.rotate(-3), Dir4::Left);
+        assert_eq!(Dir4::Up.delta::<i32>(), Point2::new(0, -1));
+        assert_eq!(Dir4::Up.step_row_col((5, 2)), (4, 2));
+        assert_eq!(Dir4::Right.step_row_col((5, 2)), (5, 3));
+        assert_eq!(Dir4::Left.code(), 2);
+        assert_eq!(Dir4::from_code(3), Some(Dir4::Up));
+        assert_eq!(Dir4::from_code(4), None);
+
+        let parsed = "UDLR^v<>NSEW"
+            .chars()
+            .map(|c| Dir4::from_char(c).unwrap().arrow())
+            .collect::<String>();
+        assert_eq!(parsed, "^v<>^v<>^v><");
+        assert_eq!(Dir4::from_char('x'), None);
+    }
+
+    #[test]
+    fn test_dir8() {
+        assert_eq!(Dir8::from(Dir4::Left), Dir8::Left);
+        assert_eq!(Dir8::UpLeft.rotate(1), Dir8::Up);
+        assert_eq!(Dir8::UpRight.turn_right(), Dir8::DownRight);
+        assert_eq!(Dir8::DownLeft.reverse(), Dir8::UpRight);
+        assert_eq!(Dir8::DownLeft.step_row_col((0i64, 0)), (1, -1));
+        assert_eq!(Dir8::from_compass("NE"), Some(Dir8::UpRight));
+        assert_eq!(
+            Dir8::from_compass("NE").unwrap().delta::<i64>(),
+            Point2::new(1, -1)
+        );
+        assert!(Dir8::ALL
+            .iter()
+            .all(|&dir| Dir8::from_code(dir.code()) == Some(dir)));
+        let total = Dir8::ALL
+            .iter()
+            .fold(Point2::origin(), |sum, dir| sum + dir.delta::<i32>());
+        assert_eq!(total, Point2::origin());
+    }
+}
@@ -0,0 +1,217 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+
+// Quotes and escapes s as a DOT identifier. Newlines become line breaks in labels.
+fn quote(s: &str) -> String {
+    let escaped = s
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{}\"", escaped)
+}
+
+fn attribute_list(attributes: &[(&str, &str)]) -> String {
+    if attributes.is_empty() {
+        return String::new();
+    }
+    let attributes = attributes
+        .iter()
+        .map(|(name, value)| format!("{}={}", name, quote(value)))
+        .collect::<Vec<_>>();
+    format!(" [{}]", attributes.join(", "))
+}
+
+// A Graphviz graph, written out in the order its statements were added
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph {
+    name: String,
+    directed: bool,
+    statements: Vec<String>,
+}
+
+impl Graph {
+    pub fn new(name: &str, directed: bool) -> Self {
+        Self {
+            name: name.to_string(),
+            directed,
+            statements: Vec::new(),
+        }
+    }
+
+    pub fn directed(name: &str) -> Self {
+        Self::new(name, true)
+    }
+
+    pub fn undirected(name: &str) -> Self {
+        Self::new(name, false)
+    }
+
+    // Sets an attribute of the graph itself, like rankdir or layout
+    pub fn attribute(&mut self, name: &str, value: &str) -> &mut Self {
+        self.statements.push(format!("{}={}", name, quote(value)));
+        self
+    }
+
+    pub fn node_defaults(&mut self, attributes: &[(&str, &str)]) -> &mut Self {
+        self.statements
+            .push(format!("node{}", attribute_list(attributes)));
+        self
+    }
+
+    pub fn edge_defaults(&mut self, attributes: &[(&str, &str)]) -> &mut Self {
+        self.statements
+            .push(format!("edge{}", attribute_list(attributes)));
+        self
+    }
+
+    pub fn node(&mut self, id: &str, attributes: &[(&str, &str)]) -> &mut Self {
+        self.statements
+            .push(format!("{}{}", quote(id), attribute_list(attributes)));
+        self
+    }
+
+    pub fn edge(&mut self, from: &str, to: &str, attributes: &[(&str, &str)]) -> &mut Self {
+        let arrow = if self.directed { "->" } else { "--" };
+        self.statements.push(format!(
+            "{} {} {}{}",
+            quote(from),
+            arrow,
+            quote(to),
+            attribute_list(attributes)
+        ));
+        self
+    }
+}
+
+impl fmt::Display for Graph {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let kind = if self.directed { "digraph" } else { "graph" };
+        writeln!(f, "{} {} {{", kind, quote(&self.name))?;
+        for statement in self.statements.iter() {
+            writeln!(f, "    {};", statement)?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DotSettings {
+    pub path: String,
+    // Mark the solution on the graph, such as a path or the valves opened
+    pub highlight: bool,
+}
+
+// Collects the graphs a day exports and writes them all to one file
+#[derive(Debug, Clone)]
+pub struct Exporter {
+    settings: DotSettings,
+    graphs: Vec<Graph>,
+}
+
+impl Exporter {
+    pub fn new(settings: DotSettings) -> Self {
+        Self {
+            settings,
+            graphs: Vec::new(),
+        }
+    }
+
+    pub fn graphs(&self) -> &[Graph] {
+        &self.graphs
+    }
+
+    pub fn write(&self, out: &mut dyn Write) -> io::Result<()> {
+        for graph in self.graphs.iter() {
+            write!(out, "{}", graph)?;
+        }
+        Ok(())
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(&self.settings.path)?);
+        self.write(&mut out)?;
+        out.flush()
+    }
+}
+
+thread_local! {
+    static EXPORTER: RefCell<Option<Exporter>> = const { RefCell::new(None) };
+}
+
+pub fn start_export(settings: DotSettings) {
+    EXPORTER.with(|exporter| *exporter.borrow_mut() = Some(Exporter::new(settings)));
+}
+
+pub fn stop_export() -> Option<Exporter> {
+    EXPORTER.with(|exporter| exporter.borrow_mut().take())
+}
+
+// Days call this with code that builds their graph, which is told whether to
+// highlight the solution. It does nothing unless an export has been started.
+pub fn export(render: impl FnOnce(bool) -> Graph) {
+    EXPORTER.with(|exporter| {
+        if let Some(exporter) = exporter.borrow_mut().as_mut() {
+            let graph = render(exporter.settings.highlight);
+            exporter.graphs.push(graph);
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_graph() {
+        let mut graph = Graph::directed("tree");
+        graph
+            .attribute("rankdir", "LR")
+            .node_defaults(&[("shape", "box")])
+            .node("/", &[])
+            .node("/a \"b\"", &[("label", "a \"b\""), ("color", "red")])
+            .edge("/", "/a \"b\"", &[]);
+        assert_eq!(
+            graph.to_string(),
+            "digraph \"tree\" {
+    rankdir=\"LR\";
+    node [shape=\"box\"];
+    \"/\";
+    \"/a \\\"b\\\"\" [label=\"a \\\"b\\\"\", color=\"red\"];
+    \"/\" -> \"/a \\\"b\\\"\";
+}
+"
+        );
+
+        let mut graph = Graph::undirected("tunnels");
+        graph.edge("AA", "BB", &[("label", "AA\nBB")]);
+        assert_eq!(
+            graph.to_string(),
+            "graph \"tunnels\" {\n    \"AA\" -- \"BB\" [label=\"AA\\nBB\"];\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_export() {
+        stop_export();
+        export(|_| panic!("Should not build graphs when not exporting"));
+
+        start_export(DotSettings {
+            path: String::new(),
+            highlight: true,
+        });
+        export(|highlight| {
+            assert!(highlight);
+            Graph::directed("a")
+        });
+        export(|_| Graph::undirected("b"));
+        let exporter = stop_export().unwrap();
+        let mut out = Vec::new();
+        exporter.write(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "digraph \"a\" {\n}\ngraph \"b\" {\n}\n"
+        );
+    }
+}
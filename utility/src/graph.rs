@@ -0,0 +1,587 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use crate::grid::Grid;
+
+// Anything the search algorithms can walk: each node knows the nodes one step
+// away and what that step costs. The nodes don't have to be listed up front,
+// so a puzzle's state space can be searched without building it first.
+pub trait Graph {
+    type Node: Clone + Eq + Hash + Ord;
+
+    fn successors(&self, node: &Self::Node) -> impl Iterator<Item = (Self::Node, i64)>;
+}
+
+// Adjacency lists of (node, edge weight) tuples
+impl Graph for [Vec<(usize, i64)>] {
+    type Node = usize;
+
+    fn successors(&self, node: &usize) -> impl Iterator<Item = (usize, i64)> {
+        self[*node].iter().copied()
+    }
+}
+
+impl Graph for Vec<Vec<(usize, i64)>> {
+    type Node = usize;
+
+    fn successors(&self, node: &usize) -> impl Iterator<Item = (usize, i64)> {
+        self.as_slice().successors(node)
+    }
+}
+
+// Adjacency lists without weights, where every edge costs 1
+impl Graph for [Vec<usize>] {
+    type Node = usize;
+
+    fn successors(&self, node: &usize) -> impl Iterator<Item = (usize, i64)> {
+        self[*node].iter().map(|&v| (v, 1))
+    }
+}
+
+impl Graph for Vec<Vec<usize>> {
+    type Node = usize;
+
+    fn successors(&self, node: &usize) -> impl Iterator<Item = (usize, i64)> {
+        self.as_slice().successors(node)
+    }
+}
+
+// Steps between orthogonally adjacent cells of a grid. cost is given the cell
+// being left and the cell being entered, and returns None if the step isn't
+// allowed.
+pub struct GridGraph<'a, T, F> {
+    grid: &'a Grid<T>,
+    cost: F,
+}
+
+impl<'a, T, F: Fn(&T, &T) -> Option<i64>> GridGraph<'a, T, F> {
+    pub fn new(grid: &'a Grid<T>, cost: F) -> Self {
+        Self { grid, cost }
+    }
+}
+
+impl<T, F: Fn(&T, &T) -> Option<i64>> Graph for GridGraph<'_, T, F> {
+    type Node = (usize, usize);
+
+    fn successors(&self, &(x, y): &(usize, usize)) -> impl Iterator<Item = ((usize, usize), i64)> {
+        let from = &self.grid[(x, y)];
+        self.grid
+            .neighbors4(x, y)
+            .filter_map(move |to| Some((to, (self.cost)(from, &self.grid[to])?)))
+    }
+}
+
+// A graph whose successors come from a closure, for state spaces that are
+// only known one step at a time
+pub struct FnGraph<N, F> {
+    successors: F,
+    node: PhantomData<fn(&N)>,
+}
+
+pub fn from_fn<N, I, F>(successors: F) -> FnGraph<N, F>
+where
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = (N, i64)>,
+{
+    FnGraph {
+        successors,
+        node: PhantomData,
+    }
+}
+
+impl<N, I, F> Graph for FnGraph<N, F>
+where
+    N: Clone + Eq + Hash + Ord,
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = (N, i64)>,
+{
+    type Node = N;
+
+    fn successors(&self, node: &N) -> impl Iterator<Item = (N, i64)> {
+        (self.successors)(node).into_iter()
+    }
+}
+
+// Distances and predecessors from the start nodes to every node reached, as
+// found by dijkstra_all, dijkstra_multi or bfs_multi
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<N> {
+    dist: HashMap<N, i64>,
+    // The node before each one on its shortest path. The starts have none.
+    prev: HashMap<N, N>,
+    // The start each node's shortest path begins at
+    source: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash> ShortestPaths<N> {
+    fn new(starts: &[N]) -> Self {
+        let mut paths = ShortestPaths {
+            dist: HashMap::new(),
+            prev: HashMap::new(),
+            source: HashMap::new(),
+        };
+        for start in starts.iter() {
+            paths.dist.insert(start.clone(), 0);
+            paths.source.insert(start.clone(), start.clone());
+        }
+        paths
+    }
+
+    fn reach(&mut self, v: &N, d_v: i64, u: &N) {
+        let source = self.source[u].clone();
+        self.dist.insert(v.clone(), d_v);
+        self.prev.insert(v.clone(), u.clone());
+        self.source.insert(v.clone(), source);
+    }
+
+    // None if end can't be reached from any start
+    pub fn cost(&self, end: &N) -> Option<i64> {
+        self.dist.get(end).copied()
+    }
+
+    // Which start reached end first
+    pub fn source(&self, end: &N) -> Option<&N> {
+        self.source.get(end)
+    }
+
+    // The nodes from the start to end, both included
+    pub fn path(&self, end: &N) -> Option<Vec<N>> {
+        self.dist.get(end)?;
+        let mut path = vec![end.clone()];
+        let mut u = end;
+        while let Some(prev) = self.prev.get(u) {
+            path.push(prev.clone());
+            u = prev;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    // Every node reached and its distance, in no particular order
+    pub fn reached(&self) -> impl Iterator<Item = (&N, i64)> {
+        self.dist.iter().map(|(node, &dist)| (node, dist))
+    }
+}
+
+// Runs Dijkstra's algorithm from all the starts at once, stopping early once
+// stop_at is reached
+fn dijkstra_search<G: Graph + ?Sized>(
+    graph: &G,
+    starts: &[G::Node],
+    stop_at: Option<&G::Node>,
+) -> ShortestPaths<G::Node> {
+    let mut paths = ShortestPaths::new(starts);
+    let mut pq = BinaryHeap::new();
+    for start in starts.iter() {
+        pq.push(Reverse((0, start.clone())));
+    }
+    while let Some(Reverse((d_u, u))) = pq.pop() {
+        if paths.cost(&u).is_some_and(|d| d_u > d) {
+            continue;
+        }
+        if stop_at == Some(&u) {
+            break;
+        }
+        for (v, w) in graph.successors(&u) {
+            let d_v = d_u + w;
+            if paths.cost(&v).is_none_or(|d| d_v < d) {
+                paths.reach(&v, d_v, &u);
+                pq.push(Reverse((d_v, v)));
+            }
+        }
+    }
+    paths
+}
+
+// Dijkstra's algorithm
+// Returns the distance and the path from start to end, both included, or
+// None if there is no path
+pub fn dijkstra<G: Graph + ?Sized>(
+    graph: &G,
+    start: G::Node,
+    end: G::Node,
+) -> Option<(i64, Vec<G::Node>)> {
+    let paths = dijkstra_search(graph, &[start], Some(&end));
+    Some((paths.cost(&end)?, paths.path(&end)?))
+}
+
+// Dijkstra's algorithm from start to every node it can reach
+pub fn dijkstra_all<G: Graph + ?Sized>(graph: &G, start: G::Node) -> ShortestPaths<G::Node> {
+    dijkstra_search(graph, &[start], None)
+}
+
+// Dijkstra's algorithm from the nearest of several starts to every node
+pub fn dijkstra_multi<G: Graph + ?Sized>(graph: &G, starts: &[G::Node]) -> ShortestPaths<G::Node> {
+    dijkstra_search(graph, starts, None)
+}
+
+// What an A* search found, and how much work it took to find it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AStar<N> {
+    pub cost: i64,
+    // The nodes from start to the goal, both included
+    pub path: Vec<N>,
+    // How many nodes were taken off the open set and had their successors
+    // looked at
+    pub expanded: usize,
+    // The largest the open set got
+    pub max_open: usize,
+}
+
+// A* search from start to the nearest node that is_goal accepts. heuristic
+// must never overestimate the cost left to reach a goal, or the path found
+// may not be the shortest.
+pub fn astar<G: Graph + ?Sized>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl Fn(&G::Node) -> bool,
+    heuristic: impl Fn(&G::Node) -> i64,
+) -> Option<AStar<G::Node>> {
+    astar_with(graph, start, is_goal, heuristic, |_, _| {})
+}
+
+// Like astar, calling on_expand with each node it expands and the cost of
+// reaching it, for watching how the search spreads out
+pub fn astar_with<G: Graph + ?Sized>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl Fn(&G::Node) -> bool,
+    heuristic: impl Fn(&G::Node) -> i64,
+    mut on_expand: impl FnMut(&G::Node, i64),
+) -> Option<AStar<G::Node>> {
+    let mut paths = ShortestPaths::new(std::slice::from_ref(&start));
+    let mut open = BinaryHeap::new();
+    // Ties on the estimate go to the node furthest along, which is usually
+    // closest to the goal
+    open.push(Reverse((heuristic(&start), Reverse(0), start)));
+    let mut expanded = 0;
+    let mut max_open = 1;
+    while let Some(Reverse((_, Reverse(g_u), u))) = open.pop() {
+        if paths.cost(&u).is_some_and(|g| g_u > g) {
+            continue;
+        }
+        if is_goal(&u) {
+            return Some(AStar {
+                cost: g_u,
+                path: paths.path(&u)?,
+                expanded,
+                max_open,
+            });
+        }
+        expanded += 1;
+        on_expand(&u, g_u);
+        for (v, w) in graph.successors(&u) {
+            let g_v = g_u + w;
+            if paths.cost(&v).is_none_or(|g| g_v < g) {
+                paths.reach(&v, g_v, &u);
+                open.push(Reverse((g_v + heuristic(&v), Reverse(g_v), v)));
+            }
+        }
+        max_open = max_open.max(open.len());
+    }
+    None
+}
+
+// Breadth first search from start, counting steps and ignoring edge weights
+pub fn bfs<G: Graph + ?Sized>(graph: &G, start: G::Node) -> ShortestPaths<G::Node> {
+    bfs_multi(graph, &[start])
+}
+
+// Breadth first search from the nearest of several starts to every node
+pub fn bfs_multi<G: Graph + ?Sized>(graph: &G, starts: &[G::Node]) -> ShortestPaths<G::Node> {
+    let mut paths = ShortestPaths::new(starts);
+    let mut queue = starts.iter().cloned().collect::<VecDeque<_>>();
+    while let Some(u) = queue.pop_front() {
+        let d_v = paths.dist[&u] + 1;
+        for (v, _) in graph.successors(&u) {
+            if !paths.dist.contains_key(&v) {
+                paths.reach(&v, d_v, &u);
+                queue.push_back(v);
+            }
+        }
+    }
+    paths
+}
+
+// Shortest distances and next hops between every pair of nodes, for graphs
+// whose nodes are numbered 0..num_nodes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllPairs {
+    dist: Vec<Vec<Option<i64>>>,
+    next: Vec<Vec<Option<usize>>>,
+}
+
+impl AllPairs {
+    fn new(num_nodes: usize) -> Self {
+        let mut dist = vec![vec![None; num_nodes]; num_nodes];
+        for (node, row) in dist.iter_mut().enumerate() {
+            row[node] = Some(0);
+        }
+        AllPairs {
+            dist,
+            next: vec![vec![None; num_nodes]; num_nodes],
+        }
+    }
+
+    pub fn num_nodes(&self) -> usize {
+        self.dist.len()
+    }
+
+    // None if to can't be reached from from
+    pub fn cost(&self, from: usize, to: usize) -> Option<i64> {
+        self.dist[from][to]
+    }
+
+    // The first node after from on a shortest path to to, or None if to is
+    // from or can't be reached
+    pub fn next_hop(&self, from: usize, to: usize) -> Option<usize> {
+        self.next[from][to]
+    }
+
+    // The nodes from from to to, both included
+    pub fn path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        self.dist[from][to]?;
+        let mut path = vec![from];
+        let mut u = from;
+        while let Some(next) = self.next[u][to] {
+            path.push(next);
+            u = next;
+        }
+        Some(path)
+    }
+
+    // The graph between just the kept nodes, where each edge is a shortest
+    // path through the nodes left out. Node i of the result is keep[i].
+    pub fn compress(&self, keep: &[usize]) -> Vec<Vec<(usize, i64)>> {
+        keep.iter()
+            .map(|&from| {
+                keep.iter()
+                    .enumerate()
+                    .filter(|&(_, &to)| to != from)
+                    .filter_map(|(i, &to)| Some((i, self.dist[from][to]?)))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+// Floyd–Warshall, for any edge weights as long as no cycle has a negative
+// total
+pub fn floyd_warshall<G: Graph<Node = usize> + ?Sized>(graph: &G, num_nodes: usize) -> AllPairs {
+    let mut all = AllPairs::new(num_nodes);
+    for u in 0..num_nodes {
+        for (v, w) in graph.successors(&u) {
+            if v != u && all.dist[u][v].is_none_or(|d| w < d) {
+                all.dist[u][v] = Some(w);
+                all.next[u][v] = Some(v);
+            }
+        }
+    }
+    for k in 0..num_nodes {
+        for i in 0..num_nodes {
+            let Some(d_ik) = all.dist[i][k] else {
+                continue;
+            };
+            for j in 0..num_nodes {
+                let Some(d_kj) = all.dist[k][j] else {
+                    continue;
+                };
+                if all.dist[i][j].is_none_or(|d| d_ik + d_kj < d) {
+                    all.dist[i][j] = Some(d_ik + d_kj);
+                    all.next[i][j] = all.next[i][k];
+                }
+            }
+        }
+    }
+    all
+}
+
+// A breadth first search from every node, which is quicker than
+// floyd_warshall on sparse graphs where every edge costs 1
+pub fn all_pairs_bfs<G: Graph<Node = usize> + ?Sized>(graph: &G, num_nodes: usize) -> AllPairs {
+    let mut all = AllPairs::new(num_nodes);
+    for from in 0..num_nodes {
+        let paths = bfs(graph, from);
+        // Nearer nodes first, so the node before each one already has its
+        // next hop
+        let mut reached = paths
+            .reached()
+            .map(|(&node, dist)| (dist, node))
+            .collect::<Vec<_>>();
+        reached.sort_unstable();
+        for (dist, to) in reached {
+            all.dist[from][to] = Some(dist);
+            if let Some(&prev) = paths.prev.get(&to) {
+                all.next[from][to] = if prev == from {
+                    Some(to)
+                } else {
+                    all.next[from][prev]
+                };
+            }
+        }
+    }
+    all
+}
+
+// Every node reachable from start, in depth first order
+pub fn dfs<G: Graph + ?Sized>(graph: &G, start: G::Node) -> Vec<G::Node> {
+    let mut visited = HashSet::new();
+    let mut order = Vec::new();
+    let mut stack = vec![start];
+    while let Some(u) = stack.pop() {
+        if !visited.insert(u.clone()) {
+            continue;
+        }
+        let successors = graph.successors(&u).collect::<Vec<_>>();
+        // Pushed in reverse so they're visited in the order they're listed
+        stack.extend(
+            successors
+                .into_iter()
+                .rev()
+                .map(|(v, _)| v)
+                .filter(|v| !visited.contains(v)),
+        );
+        order.push(u);
+    }
+    order
+}
+
+// Groups the given nodes by which ones can reach each other, for graphs where
+// every edge goes both ways. Components come out in the order their first
+// node was given.
+pub fn connected_components<G: Graph + ?Sized>(
+    graph: &G,
+    nodes: impl IntoIterator<Item = G::Node>,
+) -> Vec<Vec<G::Node>> {
+    let mut seen = HashSet::new();
+    let mut components = Vec::new();
+    for node in nodes {
+        if seen.contains(&node) {
+            continue;
+        }
+        let component = dfs(graph, node);
+        seen.extend(component.iter().cloned());
+        components.push(component);
+    }
+    components
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_grid_graph() {
+        let lines = ["..#", ".##", "..."].map(String::from);
+        let grid = Grid::parse(&lines, |c| Some(c == '#')).unwrap();
+        let open = GridGraph::new(&grid, |_, &wall| (!wall).then_some(1));
+
+        let paths = bfs(&open, (0, 0));
+        assert_eq!(paths.cost(&(2, 2)), Some(4));
+        assert_eq!(paths.cost(&(2, 0)), None);
+        assert_eq!(dfs(&open, (0, 0)).len(), 6);
+        assert_eq!(
+            dijkstra(&open, (1, 0), (0, 2)),
+            Some((3, vec![(1, 0), (0, 0), (0, 1), (0, 2)]))
+        );
+    }
+
+    #[test]
+    fn test_fn_graph() {
+        // Collatz steps, which all lead to 1
+        let collatz = from_fn(|&n: &u64| {
+            let next = if n % 2 == 0 { n / 2 } else { 3 * n + 1 };
+            (n != 1).then_some((next, 1))
+        });
+        assert_eq!(dijkstra(&collatz, 6, 1).map(|(steps, _)| steps), Some(8));
+        assert_eq!(dfs(&collatz, 3), [3, 10, 5, 16, 8, 4, 2, 1]);
+    }
+
+    #[test]
+    fn test_astar() {
+        let lines = ["....", ".##.", "...#", "#..."].map(String::from);
+        let grid = Grid::parse(&lines, |c| Some(c == '#')).unwrap();
+        let open = GridGraph::new(&grid, |_, &wall| (!wall).then_some(1));
+        let manhattan = |goal: (usize, usize)| {
+            move |&(x, y): &(usize, usize)| (x.abs_diff(goal.0) + y.abs_diff(goal.1)) as i64
+        };
+
+        let found = astar(&open, (0, 0), |&node| node == (3, 3), manhattan((3, 3))).unwrap();
+        assert_eq!(found.cost, 6);
+        assert_eq!(found.path.first(), Some(&(0, 0)));
+        assert_eq!(found.path.last(), Some(&(3, 3)));
+        assert_eq!(found.path.len(), 7);
+        assert_eq!(
+            astar(&open, (0, 0), |&node| node == (1, 1), manhattan((1, 1))),
+            None
+        );
+
+        // With no heuristic it's Dijkstra, which finds the same cost but has
+        // to look at much more of the grid
+        let grid = Grid::new(10, 10, false);
+        let open = GridGraph::new(&grid, |_, &wall| (!wall).then_some(1));
+        let found = astar(&open, (0, 0), |&node| node == (9, 9), manhattan((9, 9))).unwrap();
+        let blind = astar(&open, (0, 0), |&node| node == (9, 9), |_| 0).unwrap();
+        assert_eq!((found.cost, blind.cost), (18, 18));
+        assert!(found.expanded < 2 * blind.expanded / 3);
+
+        let mut costs = Vec::new();
+        let watched = astar_with(
+            &open,
+            (0, 0),
+            |&node| node == (9, 9),
+            |_| 0,
+            |&(x, y), cost| costs.push((x + y) as i64 == cost),
+        )
+        .unwrap();
+        assert_eq!(costs.len(), watched.expanded);
+        assert!(costs.into_iter().all(|matches| matches));
+    }
+
+    #[test]
+    fn test_all_pairs() {
+        // A square 0 - 1 - 2 - 3 - 0 with a long diagonal from 0 to 2, and 4
+        // off on its own
+        let weighted = vec![
+            vec![(1, 1), (3, 1), (2, 5)],
+            vec![(0, 1), (2, 1)],
+            vec![(1, 1), (3, 1), (0, 5)],
+            vec![(2, 1), (0, 1)],
+            vec![],
+        ];
+        let all = floyd_warshall(&weighted, 5);
+        assert_eq!(all.cost(0, 2), Some(2));
+        assert_eq!(all.cost(0, 4), None);
+        assert_eq!(all.next_hop(0, 0), None);
+        assert_eq!(all.path(3, 1).map(|path| path.len()), Some(3));
+        assert_eq!(all.path(4, 4), Some(vec![4]));
+        assert_eq!(all.path(4, 0), None);
+
+        // Every edge costs 1 without the weights, so the diagonal wins
+        let unweighted = weighted
+            .iter()
+            .map(|edges| edges.iter().map(|&(v, _)| v).collect())
+            .collect::<Vec<Vec<usize>>>();
+        let bfs_all = all_pairs_bfs(&unweighted, 5);
+        assert_eq!(bfs_all.path(0, 2), Some(vec![0, 2]));
+        assert_eq!(bfs_all.cost(1, 3), Some(2));
+        assert_eq!(floyd_warshall(&unweighted, 5).dist, bfs_all.dist);
+
+        // Just 1, 3 and 4, joined through the nodes left out
+        assert_eq!(
+            all.compress(&[1, 3, 4]),
+            [vec![(1, 2)], vec![(0, 2)], vec![]]
+        );
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let graph = vec![vec![1], vec![0], vec![3], vec![2], vec![]];
+        assert_eq!(
+            connected_components(&graph, 0..graph.len()),
+            [vec![0, 1], vec![2, 3], vec![4]]
+        );
+    }
+}
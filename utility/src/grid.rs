@@ -0,0 +1,290 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+// Offsets to the 4 neighbours, clockwise from up
+const OFFSETS4: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+// Offsets to the 8 neighbours, clockwise from up
+const OFFSETS8: [(i64, i64); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+// A dense rectangle of cells, stored row by row. Positions are (x, y) with y
+// counting down from the top row, like Image.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| cell(x, y))
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // Each line is a row and each char a cell. cell returns None for chars
+    // that can't be in the grid.
+    pub fn parse(
+        lines: &[String],
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, String> {
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            let row_width = line.chars().count();
+            if row_width != width {
+                return Err(format!(
+                    "Row {} is {} wide, expected {}",
+                    y, row_width, width
+                ));
+            }
+            for (x, c) in line.chars().enumerate() {
+                let value =
+                    cell(c).ok_or_else(|| format!("Unexpected {:?} at ({}, {})", c, x, y))?;
+                cells.push(value);
+            }
+        }
+        Ok(Self {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    // Cells are numbered row by row, which makes a handy node id for graphs
+    pub fn index_of(&self, x: usize, y: usize) -> usize {
+        y * self.width + x
+    }
+
+    pub fn position_of(&self, index: usize) -> (usize, usize) {
+        (index % self.width, index / self.width)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[self.index_of(x, y)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            let index = self.index_of(x, y);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        offsets: &'a [(i64, i64)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let (nx, ny) = (x as i64 + dx, y as i64 + dy);
+            self.contains(nx, ny).then_some((nx as usize, ny as usize))
+        })
+    }
+
+    // The positions up, right, down and left of (x, y) that are in the grid
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(x, y, &OFFSETS4)
+    }
+
+    // Like neighbors4, and the diagonals too
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(x, y, &OFFSETS8)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {} is outside the grid", x);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    // Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    // Every position and its cell, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(&mut f).collect(),
+        }
+    }
+
+    // One line per row, with a char for each cell
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&cell).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) is outside the {}x{} grid",
+                x, y, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the {}x{} grid", x, y, width, height))
+    }
+}
+
+// Writes each cell with its own Display, one line per row
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row.iter() {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::runner::catch_panic;
+
+    fn lines(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    fn digits() -> Grid<u32> {
+        Grid::parse(&lines(&["123", "456"]), |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get(3, 0), None);
+        let caught = catch_panic(|| grid[(3, 0)]).unwrap_err();
+        assert_eq!(caught.message, "(3, 0) is outside the 3x2 grid");
+        assert_eq!(grid.to_string(), "123\n456");
+        assert_eq!(
+            grid.render(|&d| if d % 2 == 0 { '#' } else { '.' }),
+            ".#.\n#.#"
+        );
+
+        assert_eq!(
+            Grid::parse(&lines(&["12", "345"]), |c| c.to_digit(10)),
+            Err("Row 1 is 3 wide, expected 2".to_string())
+        );
+        assert_eq!(
+            Grid::parse(&lines(&["12", "3x"]), |c| c.to_digit(10)),
+            Err("Unexpected 'x' at (1, 1)".to_string())
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = digits();
+        assert_eq!(grid.neighbors4(0, 0).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(
+            grid.neighbors4(1, 1).collect::<Vec<_>>(),
+            [(1, 0), (2, 1), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbors8(1, 0).collect::<Vec<_>>(),
+            [(2, 0), (2, 1), (1, 1), (0, 1), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let mut grid = digits();
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+        let columns = grid
+            .columns()
+            .map(|column| column.copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, [[1, 4], [2, 5], [3, 6]]);
+
+        grid[(0, 1)] = 9;
+        assert_eq!(grid.index_of(0, 1), 3);
+        assert_eq!(grid.position_of(3), (0, 1));
+        let nines = grid.iter().filter(|(_, &d)| d == 9).collect::<Vec<_>>();
+        assert_eq!(nines, [((0, 1), &9)]);
+        assert_eq!(grid.map(|d| d * 2)[(0, 1)], 18);
+        assert_eq!(Grid::from_fn(2, 2, |x, y| x + y).to_string(), "01\n12");
+    }
+}
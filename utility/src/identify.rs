@@ -0,0 +1,199 @@
+use crate::runner::{catch_panic, Day};
+
+// Days whose line shape matches at least this share of a file's lines are
+// reported as near misses
+pub const NEAR_MISS_CONFIDENCE: f64 = 0.5;
+
+// Longest part of a line to quote in an error
+const MAX_QUOTED_LEN: usize = 40;
+
+// Helpers for days' is_input_line checks
+
+// An integer with an optional leading minus sign
+pub fn is_int(s: &str) -> bool {
+    let digits = s.strip_prefix('-').unwrap_or(s);
+    !digits.is_empty() && digits.bytes().all(|c| c.is_ascii_digit())
+}
+
+// Exactly len integers with separator between them
+pub fn is_int_list(s: &str, separator: &str, len: usize) -> bool {
+    let mut count = 0;
+    for item in s.split(separator) {
+        if !is_int(item) {
+            return false;
+        }
+        count += 1;
+    }
+    count == len
+}
+
+// A non-empty string using only the given characters
+pub fn is_made_of(s: &str, chars: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| chars.contains(c))
+}
+
+fn quote_line(line: &str) -> String {
+    if line.chars().count() > MAX_QUOTED_LEN {
+        let start = line.chars().take(MAX_QUOTED_LEN).collect::<String>();
+        format!("{:?}...", start)
+    } else {
+        format!("{:?}", line)
+    }
+}
+
+// How well a file matches one day's input format
+#[derive(Debug, Clone, PartialEq)]
+pub struct Identification {
+    pub day: &'static str,
+    pub lines: usize,
+    pub lines_matched: usize,
+    // The first line that doesn't look like the day's input, or why the
+    // day's parser failed on it
+    pub first_error: Option<String>,
+}
+
+impl Identification {
+    // Share of lines that look like the day's input
+    pub fn confidence(&self) -> f64 {
+        if self.lines == 0 {
+            0.0
+        } else {
+            self.lines_matched as f64 / self.lines as f64
+        }
+    }
+
+    pub fn is_match(&self) -> bool {
+        self.lines > 0 && self.first_error.is_none()
+    }
+
+    pub fn is_near_miss(&self) -> bool {
+        !self.is_match() && self.confidence() >= NEAR_MISS_CONFIDENCE
+    }
+}
+
+// Checks every line against the day's line shape first, and only runs its
+// parser when they all match, since parsers can be slow on the wrong input
+pub fn identify(day: &Day, file_lines: &[String]) -> Identification {
+    let mut lines_matched = 0;
+    let mut first_error = None;
+    for (index, line) in file_lines.iter().enumerate() {
+        if (day.is_input_line)(line) {
+            lines_matched += 1;
+        } else if first_error.is_none() {
+            first_error = Some(format!(
+                "line {} doesn't match: {}",
+                index + 1,
+                quote_line(line)
+            ));
+        }
+    }
+
+    if first_error.is_none() && !file_lines.is_empty() {
+        if let Err(caught) = catch_panic(|| day.solution.parse(file_lines)) {
+            first_error = Some(format!("parser panicked: {}", caught));
+        }
+    }
+
+    Identification {
+        day: day.name,
+        lines: file_lines.len(),
+        lines_matched,
+        first_error,
+    }
+}
+
+// Identifies the file against every day, best matches first
+pub fn identify_all(days: &[&Day], file_lines: &[String]) -> Vec<Identification> {
+    let mut identifications = days
+        .iter()
+        .map(|day| identify(day, file_lines))
+        .collect::<Vec<_>>();
+    identifications.sort_by(|a, b| {
+        b.is_match()
+            .cmp(&a.is_match())
+            .then(b.confidence().total_cmp(&a.confidence()))
+    });
+    identifications
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::catalog::Info;
+    use crate::runner::{test_day, Solution};
+
+    fn parse_numbers(file_lines: &[String]) -> Vec<u8> {
+        file_lines
+            .iter()
+            .map(|line| line.parse().expect("Number too big"))
+            .collect()
+    }
+
+    fn sum(numbers: &[u8]) -> String {
+        numbers.iter().map(|&n| n as u32).sum::<u32>().to_string()
+    }
+
+    fn is_number_line(line: &str) -> bool {
+        is_int(line)
+    }
+
+    fn is_point_line(line: &str) -> bool {
+        is_int_list(line, ",", 2)
+    }
+
+    const NUMBERS: Day = Day {
+        name: "numbers",
+        info: Info::new("Numbers"),
+        is_input_line: is_number_line,
+        ..test_day(&Solution {
+            parse: parse_numbers,
+            part1: sum,
+            part2: sum,
+        })
+    };
+
+    const POINTS: Day = Day {
+        name: "points",
+        is_input_line: is_point_line,
+        ..NUMBERS
+    };
+
+    fn lines(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn test_helpers() {
+        assert!(is_int("-12") && is_int("0"));
+        assert!(!is_int("") && !is_int("-") && !is_int("1.5"));
+        assert!(is_int_list("1,-2,3", ",", 3));
+        assert!(!is_int_list("1,2", ",", 3) && !is_int_list("1,,3", ",", 3));
+        assert!(is_made_of("<><", "<>"));
+        assert!(!is_made_of("", "<>") && !is_made_of("<x", "<>"));
+    }
+
+    #[test]
+    fn test_identify() {
+        let file_lines = lines(&["1", "2", "3"]);
+        let found = identify_all(&[&POINTS, &NUMBERS], &file_lines);
+        assert_eq!(found[0].day, "numbers");
+        assert!(found[0].is_match());
+        assert_eq!(found[1].lines_matched, 0);
+        assert!(!found[1].is_near_miss());
+
+        let numbers = identify(&NUMBERS, &lines(&["1", "2", "1,2"]));
+        assert!(numbers.is_near_miss());
+        assert_eq!(
+            numbers.first_error.as_deref(),
+            Some("line 3 doesn't match: \"1,2\"")
+        );
+
+        let numbers = identify(&NUMBERS, &lines(&["1", "300"]));
+        assert_eq!(numbers.confidence(), 1.0);
+        assert!(numbers.is_near_miss());
+        assert!(numbers
+            .first_error
+            .unwrap()
+            .starts_with("parser panicked: Number too big"));
+    }
+}
@@ -0,0 +1,404 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+
+use crate::grid::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const RED: Rgb = Rgb(220, 40, 40);
+    pub const GREEN: Rgb = Rgb(40, 160, 60);
+    pub const BLUE: Rgb = Rgb(50, 90, 220);
+    pub const YELLOW: Rgb = Rgb(240, 200, 60);
+    pub const GRAY: Rgb = Rgb(110, 110, 110);
+
+    // Linear interpolation, t = 0 gives a and t = 1 gives b
+    pub fn lerp(a: Rgb, b: Rgb, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Rgb(mix(a.0, b.0), mix(a.1, b.1), mix(a.2, b.2))
+    }
+}
+
+// Decides the color of a single grid cell
+pub trait Palette<T> {
+    fn color(&self, cell: &T) -> Rgb;
+}
+
+impl<T, F: Fn(&T) -> Rgb> Palette<T> for F {
+    fn color(&self, cell: &T) -> Rgb {
+        self(cell)
+    }
+}
+
+// Maps numbers in min..=max evenly across a list of color stops
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    min: f64,
+    max: f64,
+    stops: Vec<Rgb>,
+}
+
+impl Gradient {
+    pub fn new(min: f64, max: f64, stops: &[Rgb]) -> Self {
+        assert!(!stops.is_empty(), "A gradient needs at least one color");
+        Self {
+            min,
+            max,
+            stops: stops.to_vec(),
+        }
+    }
+
+    // Black through red and yellow to white
+    pub fn heat(min: f64, max: f64) -> Self {
+        Self::new(min, max, &[Rgb::BLACK, Rgb::RED, Rgb::YELLOW, Rgb::WHITE])
+    }
+
+    // Low green valleys up to white peaks
+    pub fn terrain(min: f64, max: f64) -> Self {
+        Self::new(
+            min,
+            max,
+            &[Rgb(20, 80, 30), Rgb::GREEN, Rgb(150, 110, 60), Rgb::WHITE],
+        )
+    }
+
+    pub fn at(&self, value: f64) -> Rgb {
+        if self.stops.len() == 1 || self.max <= self.min {
+            return self.stops[0];
+        }
+        let t = ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0);
+        let scaled = t * (self.stops.len() - 1) as f64;
+        let index = (scaled.floor() as usize).min(self.stops.len() - 2);
+        Rgb::lerp(
+            self.stops[index],
+            self.stops[index + 1],
+            scaled - index as f64,
+        )
+    }
+}
+
+macro_rules! gradient_palette {
+    ($($t:ty),*) => {
+        $(
+            impl Palette<$t> for Gradient {
+                fn color(&self, cell: &$t) -> Rgb {
+                    self.at(*cell as f64)
+                }
+            }
+        )*
+    };
+}
+
+gradient_palette!(u8, i32, i64, u64, usize, f64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+}
+
+impl ImageFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Png => "png",
+        }
+    }
+}
+
+impl std::str::FromStr for ImageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ppm" => Ok(ImageFormat::Ppm),
+            "png" => Ok(ImageFormat::Png),
+            _ => Err(format!("Unknown image format: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, color: impl Fn(usize, usize) -> Rgb) -> Self {
+        let mut image = Self::new(width, height, Rgb::BLACK);
+        for y in 0..height {
+            for x in 0..width {
+                image.set(x, y, color(x, y));
+            }
+        }
+        image
+    }
+
+    // One pixel per cell
+    pub fn from_grid<T>(grid: &Grid<T>, palette: &impl Palette<T>) -> Self {
+        Self::from_fn(grid.width(), grid.height(), |x, y| {
+            palette.color(&grid[(x, y)])
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<Rgb> {
+        if x < self.width && y < self.height {
+            Some(self.pixels[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    // Pixels outside the image are ignored
+    pub fn set(&mut self, x: usize, y: usize, color: Rgb) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = color;
+        }
+    }
+
+    // Each pixel becomes a factor x factor block, so small grids stay visible
+    pub fn scaled(&self, factor: usize) -> Image {
+        let factor = factor.max(1);
+        Image::from_fn(self.width * factor, self.height * factor, |x, y| {
+            self.pixels[(y / factor) * self.width + x / factor]
+        })
+    }
+
+    // Binary PPM (P6)
+    pub fn write_ppm(&self, out: &mut dyn Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        let bytes = self
+            .pixels
+            .iter()
+            .flat_map(|pixel| [pixel.0, pixel.1, pixel.2])
+            .collect::<Vec<_>>();
+        out.write_all(&bytes)
+    }
+
+    #[cfg(feature = "png")]
+    pub fn write_png(&self, out: &mut dyn Write) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let bytes = self
+            .pixels
+            .iter()
+            .flat_map(|pixel| [pixel.0, pixel.1, pixel.2])
+            .collect::<Vec<_>>();
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&bytes))
+            .map_err(io::Error::other)
+    }
+
+    #[cfg(not(feature = "png"))]
+    pub fn write_png(&self, _out: &mut dyn Write) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "PNG output requires the utility crate's `png` feature",
+        ))
+    }
+
+    pub fn write(&self, format: ImageFormat, out: &mut dyn Write) -> io::Result<()> {
+        match format {
+            ImageFormat::Ppm => self.write_ppm(out),
+            ImageFormat::Png => self.write_png(out),
+        }
+    }
+
+    pub fn save(&self, format: ImageFormat, path: &str) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(path)?);
+        self.write(format, &mut out)?;
+        out.flush()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageSettings {
+    // Output files are named <prefix>-<name>.<ext>
+    pub prefix: String,
+    pub format: ImageFormat,
+    // Size in pixels of each grid cell
+    pub scale: usize,
+    // Only keep one out of every this many animation frames
+    pub every: usize,
+}
+
+impl Default for ImageSettings {
+    fn default() -> Self {
+        Self {
+            prefix: "image".to_string(),
+            format: ImageFormat::Ppm,
+            scale: 1,
+            every: 1,
+        }
+    }
+}
+
+// Writes snapshots and numbered animation frames as the simulation runs
+#[derive(Debug, Clone)]
+pub struct Exporter {
+    settings: ImageSettings,
+    frames_seen: HashMap<String, usize>,
+    files_written: Vec<String>,
+}
+
+impl Exporter {
+    pub fn new(settings: ImageSettings) -> Self {
+        Self {
+            settings,
+            frames_seen: HashMap::new(),
+            files_written: Vec::new(),
+        }
+    }
+
+    pub fn files_written(&self) -> &[String] {
+        &self.files_written
+    }
+
+    fn write(&mut self, file_name: String, image: Image) {
+        let path = format!(
+            "{}-{}.{}",
+            self.settings.prefix,
+            file_name,
+            self.settings.format.extension()
+        );
+        image
+            .scaled(self.settings.scale)
+            .save(self.settings.format, &path)
+            .expect("Unable to write image file");
+        self.files_written.push(path);
+    }
+
+    pub fn snapshot(&mut self, name: &str, render: impl FnOnce() -> Image) {
+        self.write(name.to_string(), render());
+    }
+
+    // Frames are numbered per name, so a day can write several animations
+    pub fn frame(&mut self, name: &str, render: impl FnOnce() -> Image) {
+        let frames_seen = self.frames_seen.entry(name.to_string()).or_insert(0);
+        let index = *frames_seen;
+        *frames_seen += 1;
+        if !index.is_multiple_of(self.settings.every.max(1)) {
+            return;
+        }
+        let frame_number = index / self.settings.every.max(1);
+        self.write(format!("{}-{:05}", name, frame_number), render());
+    }
+}
+
+thread_local! {
+    static EXPORTER: RefCell<Option<Exporter>> = const { RefCell::new(None) };
+}
+
+pub fn start_export(settings: ImageSettings) {
+    EXPORTER.with(|exporter| *exporter.borrow_mut() = Some(Exporter::new(settings)));
+}
+
+pub fn stop_export() -> Option<Exporter> {
+    EXPORTER.with(|exporter| exporter.borrow_mut().take())
+}
+
+// Both of these do nothing unless an export has been started
+pub fn snapshot(name: &str, render: impl FnOnce() -> Image) {
+    EXPORTER.with(|exporter| {
+        if let Some(exporter) = exporter.borrow_mut().as_mut() {
+            exporter.snapshot(name, render);
+        }
+    });
+}
+
+pub fn frame(name: &str, render: impl FnOnce() -> Image) {
+    EXPORTER.with(|exporter| {
+        if let Some(exporter) = exporter.borrow_mut().as_mut() {
+            exporter.frame(name, render);
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_gradient() {
+        let gradient = Gradient::new(0.0, 10.0, &[Rgb::BLACK, Rgb::WHITE]);
+        assert_eq!(gradient.at(0.0), Rgb::BLACK);
+        assert_eq!(gradient.at(5.0), Rgb(128, 128, 128));
+        assert_eq!(gradient.at(10.0), Rgb::WHITE);
+        assert_eq!(gradient.at(20.0), Rgb::WHITE);
+
+        let gradient = Gradient::new(0.0, 2.0, &[Rgb::RED, Rgb::GREEN, Rgb::BLUE]);
+        assert_eq!(gradient.color(&1), Rgb::GREEN);
+        assert_eq!(gradient.color(&2usize), Rgb::BLUE);
+    }
+
+    #[test]
+    fn test_ppm() {
+        let grid = Grid::from_fn(2, 2, |x, y| x == y);
+        let palette = |cell: &bool| if *cell { Rgb::WHITE } else { Rgb::BLACK };
+        let image = Image::from_grid(&grid, &palette);
+        assert_eq!(image.get(1, 1), Some(Rgb::WHITE));
+        assert_eq!(image.get(2, 1), None);
+
+        let mut out = Vec::new();
+        image.write_ppm(&mut out).unwrap();
+        assert_eq!(&out[..11], b"P6\n2 2\n255\n");
+        assert_eq!(out.len(), 11 + 2 * 2 * 3);
+        assert_eq!(&out[11..14], &[255, 255, 255]);
+
+        let scaled = image.scaled(3);
+        assert_eq!((scaled.width(), scaled.height()), (6, 6));
+        assert_eq!(scaled.get(5, 5), Some(Rgb::WHITE));
+        assert_eq!(scaled.get(3, 2), Some(Rgb::BLACK));
+    }
+
+    #[test]
+    fn test_frame_numbering() {
+        let dir = std::env::temp_dir().join(format!("utility-image-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let prefix = dir.join("test").to_str().unwrap().to_string();
+        let mut exporter = Exporter::new(ImageSettings {
+            prefix: prefix.clone(),
+            every: 2,
+            ..Default::default()
+        });
+        for _ in 0..5 {
+            exporter.frame("anim", || Image::new(1, 1, Rgb::RED));
+        }
+        exporter.snapshot("final", || Image::new(1, 1, Rgb::BLUE));
+
+        let expected = [
+            format!("{}-anim-00000.ppm", prefix),
+            format!("{}-anim-00001.ppm", prefix),
+            format!("{}-anim-00002.ppm", prefix),
+            format!("{}-final.ppm", prefix),
+        ];
+        assert_eq!(exporter.files_written(), expected);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,279 @@
+use std::fmt;
+
+// The integers from start to end, including both, like the ranges puzzles give
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Self {
+        assert!(start <= end, "Interval {}..={} is empty", start, end);
+        Self { start, end }
+    }
+
+    pub fn point(x: i64) -> Self {
+        Self::new(x, x)
+    }
+
+    // Number of integers in the interval
+    pub fn size(&self) -> i64 {
+        self.end - self.start + 1
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        self.start <= x && x <= self.end
+    }
+
+    pub fn contains_interval(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn intersects(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    // Whether the two could be merged into one interval without a gap
+    fn touches(&self, other: &Interval) -> bool {
+        self.start <= other.end.saturating_add(1) && other.start <= self.end.saturating_add(1)
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}..={}", self.start, self.end)
+    }
+}
+
+// A set of integers stored as the fewest intervals that cover it, sorted and
+// with gaps between each one
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Adds every integer in the interval, merging it with the intervals it
+    // overlaps or touches
+    pub fn insert(&mut self, interval: Interval) {
+        let first = self
+            .intervals
+            .partition_point(|existing| existing.end.saturating_add(1) < interval.start);
+        let mut merged = interval;
+        let mut last = first;
+        while last < self.intervals.len() && self.intervals[last].touches(&merged) {
+            merged.start = merged.start.min(self.intervals[last].start);
+            merged.end = merged.end.max(self.intervals[last].end);
+            last += 1;
+        }
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    // Takes every integer in the interval out of the set
+    pub fn remove(&mut self, interval: Interval) {
+        let mut remaining = Vec::with_capacity(self.intervals.len() + 1);
+        for existing in self.intervals.drain(..) {
+            if !existing.intersects(&interval) {
+                remaining.push(existing);
+                continue;
+            }
+            if existing.start < interval.start {
+                remaining.push(Interval::new(existing.start, interval.start - 1));
+            }
+            if interval.end < existing.end {
+                remaining.push(Interval::new(interval.end + 1, existing.end));
+            }
+        }
+        self.intervals = remaining;
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        self.intervals
+            .iter()
+            .chain(other.intervals.iter())
+            .copied()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut result = IntervalSet::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (self.intervals[i], other.intervals[j]);
+            if let Some(both) = a.intersection(&b) {
+                result.intervals.push(both);
+            }
+            // Whichever ends first can't overlap anything further on
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        result
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut result = self.clone();
+        for &interval in &other.intervals {
+            result.remove(interval);
+        }
+        result
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        let i = self.intervals.partition_point(|interval| interval.end < x);
+        self.intervals
+            .get(i)
+            .is_some_and(|interval| interval.contains(x))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    // Number of integers in the set
+    pub fn covered(&self) -> i64 {
+        self.intervals.iter().map(Interval::size).sum()
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    // The stretches of within that the set doesn't cover, in order
+    pub fn gaps(&self, within: Interval) -> impl Iterator<Item = Interval> + '_ {
+        let mut next_start = Some(within.start);
+        self.intervals
+            .iter()
+            .filter_map(move |interval| interval.intersection(&within))
+            .map(Some)
+            .chain([None])
+            .filter_map(move |covered| {
+                let start = next_start?;
+                let gap_end = match covered {
+                    Some(covered) => {
+                        next_start = covered.end.checked_add(1).filter(|&x| x <= within.end);
+                        covered.start - 1
+                    }
+                    None => {
+                        next_start = None;
+                        within.end
+                    }
+                };
+                (start <= gap_end).then(|| Interval::new(start, gap_end))
+            })
+    }
+}
+
+// Sorts the intervals first, so building a set this way takes n log n time
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut sorted = iter.into_iter().collect::<Vec<_>>();
+        sorted.sort_unstable();
+        let mut intervals: Vec<Interval> = Vec::with_capacity(sorted.len());
+        for interval in sorted {
+            match intervals.last_mut() {
+                Some(last) if last.touches(&interval) => last.end = last.end.max(interval.end),
+                _ => intervals.push(interval),
+            }
+        }
+        Self { intervals }
+    }
+}
+
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let parts = self
+            .intervals
+            .iter()
+            .map(Interval::to_string)
+            .collect::<Vec<_>>();
+        write!(f, "{{{}}}", parts.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let a = Interval::new(2, 8);
+        let b = Interval::new(3, 7);
+        assert_eq!(a.size(), 7);
+        assert!(a.contains_interval(&b));
+        assert!(!b.contains_interval(&a));
+        assert!(a.intersects(&Interval::point(8)));
+        assert!(!a.intersects(&Interval::point(9)));
+        assert_eq!(
+            a.intersection(&Interval::new(6, 10)),
+            Some(Interval::new(6, 8))
+        );
+        assert_eq!(a.intersection(&Interval::new(9, 10)), None);
+    }
+
+    #[test]
+    fn test_insert_and_remove() {
+        let mut intervals = IntervalSet::new();
+        intervals.insert(Interval::new(10, 12));
+        intervals.insert(Interval::new(1, 3));
+        intervals.insert(Interval::new(6, 7));
+        assert_eq!(intervals.to_string(), "{1..=3, 6..=7, 10..=12}");
+        // Touching intervals merge, and so does everything in between
+        intervals.insert(Interval::new(4, 10));
+        assert_eq!(intervals, set(&[(1, 12)]));
+        assert_eq!(intervals.covered(), 12);
+
+        intervals.remove(Interval::new(5, 6));
+        intervals.remove(Interval::point(12));
+        assert_eq!(intervals, set(&[(1, 4), (7, 11)]));
+        assert!(intervals.contains(4));
+        assert!(!intervals.contains(5));
+        assert!(!intervals.contains(12));
+        intervals.remove(Interval::new(0, 20));
+        assert!(intervals.is_empty());
+
+        assert_eq!(set(&[(5, 9), (1, 2), (3, 4), (8, 12)]), set(&[(1, 12)]));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(1, 5), (10, 15)]);
+        let b = set(&[(4, 11), (14, 20)]);
+        assert_eq!(a.union(&b), set(&[(1, 20)]));
+        assert_eq!(a.intersection(&b), set(&[(4, 5), (10, 11), (14, 15)]));
+        assert_eq!(a.difference(&b), set(&[(1, 3), (12, 13)]));
+        assert_eq!(b.difference(&a), set(&[(6, 9), (16, 20)]));
+
+        let gaps = a.gaps(Interval::new(0, 20)).collect::<Vec<_>>();
+        assert_eq!(gaps, set(&[(0, 0), (6, 9), (16, 20)]).intervals());
+        let gaps = a.gaps(Interval::new(3, 12)).collect::<Vec<_>>();
+        assert_eq!(gaps, [Interval::new(6, 9)]);
+        assert_eq!(a.gaps(Interval::new(11, 14)).count(), 0);
+        assert_eq!(
+            IntervalSet::new()
+                .gaps(Interval::new(1, 2))
+                .collect::<Vec<_>>(),
+            [Interval::new(1, 2)]
+        );
+    }
+}
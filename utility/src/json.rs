@@ -0,0 +1,178 @@
+// Minimal helpers for writing JSON by hand
+
+// Quotes and escapes s as a JSON string
+pub fn string(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() + 2);
+    result.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+// Values that can be written out as JSON
+pub trait ToJson {
+    fn to_json(&self) -> String;
+}
+
+macro_rules! impl_to_json_number {
+    ($($t:ty),*) => {
+        $(impl ToJson for $t {
+            fn to_json(&self) -> String {
+                self.to_string()
+            }
+        })*
+    };
+}
+
+impl_to_json_number!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl ToJson for f64 {
+    fn to_json(&self) -> String {
+        if self.is_finite() {
+            self.to_string()
+        } else {
+            "null".to_string()
+        }
+    }
+}
+
+impl ToJson for bool {
+    fn to_json(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ToJson for str {
+    fn to_json(&self) -> String {
+        string(self)
+    }
+}
+
+impl ToJson for String {
+    fn to_json(&self) -> String {
+        string(self)
+    }
+}
+
+impl<T: ToJson + ?Sized> ToJson for &T {
+    fn to_json(&self) -> String {
+        (*self).to_json()
+    }
+}
+
+impl<T: ToJson> ToJson for Option<T> {
+    fn to_json(&self) -> String {
+        match self {
+            Some(value) => value.to_json(),
+            None => "null".to_string(),
+        }
+    }
+}
+
+impl<T: ToJson> ToJson for [T] {
+    fn to_json(&self) -> String {
+        let items = self.iter().map(|item| item.to_json()).collect::<Vec<_>>();
+        format!("[{}]", items.join(","))
+    }
+}
+
+impl<T: ToJson> ToJson for Vec<T> {
+    fn to_json(&self) -> String {
+        self.as_slice().to_json()
+    }
+}
+
+impl<T: ToJson, const N: usize> ToJson for [T; N] {
+    fn to_json(&self) -> String {
+        self.as_slice().to_json()
+    }
+}
+
+// Tuples are written as arrays, so a position (x, y) becomes [x,y]
+impl<A: ToJson, B: ToJson> ToJson for (A, B) {
+    fn to_json(&self) -> String {
+        format!("[{},{}]", self.0.to_json(), self.1.to_json())
+    }
+}
+
+impl<A: ToJson, B: ToJson, C: ToJson> ToJson for (A, B, C) {
+    fn to_json(&self) -> String {
+        format!(
+            "[{},{},{}]",
+            self.0.to_json(),
+            self.1.to_json(),
+            self.2.to_json()
+        )
+    }
+}
+
+// A JSON object built up one field at a time, in order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Object {
+    fields: Vec<(String, String)>,
+}
+
+impl Object {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field(mut self, name: &str, value: impl ToJson) -> Self {
+        self.fields.push((name.to_string(), value.to_json()));
+        self
+    }
+
+    // Adds the fields of other after this object's fields
+    pub fn extend(mut self, other: Object) -> Self {
+        self.fields.extend(other.fields);
+        self
+    }
+}
+
+impl ToJson for Object {
+    fn to_json(&self) -> String {
+        let fields = self
+            .fields
+            .iter()
+            .map(|(name, value)| format!("{}:{}", string(name), value))
+            .collect::<Vec<_>>();
+        format!("{{{}}}", fields.join(","))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_string() {
+        assert_eq!(string("abc"), "\"abc\"");
+        assert_eq!(string("a\"b\\c"), "\"a\\\"b\\\\c\"");
+        assert_eq!(string("\x1b[H\r\n"), "\"\\u001b[H\\r\\n\"");
+    }
+
+    #[test]
+    fn test_object() {
+        let object = Object::new()
+            .field("name", "elf")
+            .field("pos", (1, -2))
+            .field("path", vec![(0, 0), (0, 1)])
+            .field("done", Some(true))
+            .field("best", None::<u64>);
+        assert_eq!(
+            object.to_json(),
+            "{\"name\":\"elf\",\"pos\":[1,-2],\"path\":[[0,0],[0,1]],\"done\":true,\"best\":null}"
+        );
+        assert_eq!(Object::new().to_json(), "{}");
+    }
+}
@@ -1,5 +1,27 @@
 use std::{fs::File, io::Read};
 
+pub mod asciicast;
+pub mod audit;
+pub mod batch;
+pub mod catalog;
+pub mod checkpoint;
+pub mod cycle;
+pub mod dir;
+pub mod dot;
+pub mod graph;
+pub mod grid;
+pub mod identify;
+pub mod image;
+pub mod interval;
+pub mod json;
+pub mod metrics;
+pub mod point;
+pub mod runner;
+pub mod scaling;
+pub mod span;
+pub mod trace;
+pub mod visualizer;
+
 pub fn read_file(file: &str) -> String {
     let mut file = File::open(file).expect("Unable to open file");
     let mut contents = String::new();
@@ -106,45 +128,10 @@ pub fn crt(a: &[i64], m: &[i64]) -> i64 {
 }
 
 // Graph theory
-
-// Dijkstra's algorithm
-// Returns a vector of (distance, previous node) tuples
-// The distance is std::i64::MAX if there is no path
-// The graph is represented as an adjacency list
-// Each element of the adjacency list is a tuple of (node, edge weight)
-pub fn dijkstra(
-    graph: &Vec<Vec<(usize, i64)>>,
-    start: usize,
-    end: usize,
-) -> (i64, Vec<usize>) {
-    let mut dist = vec![std::i64::MAX; graph.len()];
-    let mut prev = vec![0; graph.len()];
-    let mut pq = std::collections::BinaryHeap::new();
-    dist[start] = 0;
-    pq.push(std::cmp::Reverse((0, start)));
-    while let Some(std::cmp::Reverse((d_u, u))) = pq.pop() {
-        if d_u > dist[u] {
-            continue;
-        }
-        for &(v, w) in &graph[u] {
-            let d_v = d_u + w;
-            if d_v < dist[v] {
-                dist[v] = d_v;
-                prev[v] = u;
-                pq.push(std::cmp::Reverse((d_v, v)));
-            }
-        }
-    }
-    let mut path = Vec::new();
-    let mut u = end;
-    while u != start {
-        path.push(u);
-        u = prev[u];
-    }
-    path.push(start);
-    path.reverse();
-    (dist[end], path)
-}
+pub use graph::{
+    all_pairs_bfs, astar, astar_with, bfs, bfs_multi, dfs, dijkstra, dijkstra_all, dijkstra_multi,
+    floyd_warshall, Graph,
+};
 
 // Tests
 #[cfg(test)]
@@ -231,8 +218,60 @@ mod test {
             vec![(3, 6), (5, 9)],
             vec![(0, 14), (2, 2), (4, 9)],
         ];
-        let (dist, path) = dijkstra(&graph, 0, 4);
+        let (dist, path) = dijkstra(&graph, 0, 4).unwrap();
         assert_eq!(dist, 20);
         assert_eq!(path, vec![0, 2, 5, 4]);
+
+        let paths = dijkstra_all(&graph, 0);
+        assert_eq!(paths.cost(&3), Some(20));
+        assert_eq!(paths.path(&5), Some(vec![0, 2, 5]));
+        assert_eq!(paths.path(&0), Some(vec![0]));
+    }
+
+    #[test]
+    fn test_dijkstra_unreachable() {
+        // Node 0 can't be reached from 1, and 3 can't be reached at all
+        let graph = vec![vec![(1, 1)], vec![(2, 1)], vec![(1, 1)], vec![(0, 1)]];
+        assert_eq!(dijkstra(&graph, 1, 3), None);
+        assert_eq!(dijkstra(&graph, 1, 0), None);
+        assert_eq!(dijkstra(&graph, 0, 2), Some((2, vec![0, 1, 2])));
+
+        let paths = dijkstra_all(&graph, 1);
+        let dist = (0..4).map(|node| paths.cost(&node)).collect::<Vec<_>>();
+        assert_eq!(dist, [None, Some(0), Some(1), None]);
+        assert_eq!(paths.path(&3), None);
+    }
+
+    #[test]
+    fn test_multi_source() {
+        // A line of nodes 0 - 1 - 2 - 3 - 4 - 5, with 2 and 3 ten times as far
+        // apart when weighted
+        let weighted = vec![
+            vec![(1, 1)],
+            vec![(0, 1), (2, 1)],
+            vec![(1, 1), (3, 10)],
+            vec![(2, 10), (4, 1)],
+            vec![(3, 1), (5, 1)],
+            vec![(4, 1)],
+        ];
+        let paths = dijkstra_multi(&weighted, &[0, 5]);
+        assert_eq!(paths.cost(&2), Some(2));
+        assert_eq!(paths.source(&2), Some(&0));
+        assert_eq!(paths.cost(&3), Some(2));
+        assert_eq!(paths.source(&3), Some(&5));
+        assert_eq!(paths.path(&3), Some(vec![5, 4, 3]));
+
+        let unweighted = weighted
+            .iter()
+            .map(|edges| edges.iter().map(|&(v, _)| v).collect())
+            .collect::<Vec<Vec<usize>>>();
+        let paths = bfs_multi(&unweighted, &[0, 5]);
+        let dist = (0..6).map(|node| paths.cost(&node)).collect::<Vec<_>>();
+        assert_eq!(dist, [0, 1, 2, 2, 1, 0].map(Some));
+        let source = (0..6)
+            .map(|node| paths.source(&node).copied())
+            .collect::<Vec<_>>();
+        assert_eq!(source, [0, 0, 0, 5, 5, 5].map(Some));
+        assert_eq!(bfs_multi(&unweighted, &[]).cost(&0), None);
     }
 }
\ No newline at end of file
@@ -0,0 +1,192 @@
+use std::cell::RefCell;
+use std::io::{self, Write};
+
+use crate::json::{Object, ToJson};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    // A running total, like the number of states expanded
+    Counter(u64),
+    // A level, like the size of a queue
+    Gauge(i64),
+}
+
+impl ToJson for Metric {
+    fn to_json(&self) -> String {
+        match self {
+            Metric::Counter(value) => value.to_json(),
+            Metric::Gauge(value) => value.to_json(),
+        }
+    }
+}
+
+// Named metrics, in the order they were first recorded
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Metrics {
+    entries: Vec<(&'static str, Metric)>,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn entry(&mut self, name: &'static str, initial: Metric) -> &mut Metric {
+        let index = match self.entries.iter().position(|(entry, _)| *entry == name) {
+            Some(index) => index,
+            None => {
+                self.entries.push((name, initial));
+                self.entries.len() - 1
+            }
+        };
+        &mut self.entries[index].1
+    }
+
+    pub fn add(&mut self, name: &'static str, amount: u64) {
+        match self.entry(name, Metric::Counter(0)) {
+            Metric::Counter(value) => *value += amount,
+            Metric::Gauge(_) => panic!("Metric {} is a gauge, not a counter", name),
+        }
+    }
+
+    pub fn set_gauge(&mut self, name: &'static str, value: i64) {
+        match self.entry(name, Metric::Gauge(value)) {
+            Metric::Gauge(gauge) => *gauge = value,
+            Metric::Counter(_) => panic!("Metric {} is a counter, not a gauge", name),
+        }
+    }
+
+    // Raises the gauge to value if it's higher, to track a peak
+    pub fn max_gauge(&mut self, name: &'static str, value: i64) {
+        match self.entry(name, Metric::Gauge(value)) {
+            Metric::Gauge(gauge) => *gauge = (*gauge).max(value),
+            Metric::Counter(_) => panic!("Metric {} is a counter, not a gauge", name),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<Metric> {
+        self.entries
+            .iter()
+            .find(|(entry, _)| *entry == name)
+            .map(|(_, metric)| *metric)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &(&'static str, Metric)> {
+        self.entries.iter()
+    }
+
+    // Writes one metric per line
+    pub fn write(&self, out: &mut dyn Write, indent: usize) -> io::Result<()> {
+        for (name, metric) in self.entries.iter() {
+            write!(out, "{:indent$}{}: ", "", name, indent = indent)?;
+            match metric {
+                Metric::Counter(value) => writeln!(out, "{}", value)?,
+                Metric::Gauge(value) => writeln!(out, "{}", value)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+// Written as an object with a field for each metric
+impl ToJson for Metrics {
+    fn to_json(&self) -> String {
+        self.entries
+            .iter()
+            .fold(Object::new(), |object, (name, metric)| {
+                object.field(name, metric)
+            })
+            .to_json()
+    }
+}
+
+thread_local! {
+    static METRICS: RefCell<Option<Metrics>> = const { RefCell::new(None) };
+}
+
+pub fn start_collecting() {
+    METRICS.with(|metrics| *metrics.borrow_mut() = Some(Metrics::new()));
+}
+
+pub fn stop_collecting() -> Option<Metrics> {
+    METRICS.with(|metrics| metrics.borrow_mut().take())
+}
+
+fn with_metrics(f: impl FnOnce(&mut Metrics)) {
+    METRICS.with(|metrics| {
+        if let Some(metrics) = metrics.borrow_mut().as_mut() {
+            f(metrics);
+        }
+    });
+}
+
+// Days call these to record metrics. They do nothing unless the runner is
+// collecting metrics, so they're cheap to leave in.
+
+pub fn count(name: &'static str) {
+    add(name, 1);
+}
+
+pub fn add(name: &'static str, amount: u64) {
+    with_metrics(|metrics| metrics.add(name, amount));
+}
+
+pub fn gauge(name: &'static str, value: i64) {
+    with_metrics(|metrics| metrics.set_gauge(name, value));
+}
+
+pub fn gauge_max(name: &'static str, value: i64) {
+    with_metrics(|metrics| metrics.max_gauge(name, value));
+}
+
+// The counter's total so far, or 0 if it hasn't been counted
+pub fn counter(name: &str) -> u64 {
+    METRICS
+        .with(|metrics| match metrics.borrow().as_ref()?.get(name)? {
+            Metric::Counter(value) => Some(value),
+            Metric::Gauge(_) => None,
+        })
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_metrics() {
+        stop_collecting();
+        count("ignored");
+        assert_eq!(counter("ignored"), 0);
+
+        start_collecting();
+        for size in [3, 7, 2] {
+            count("expanded");
+            gauge("queue", size);
+            gauge_max("max_queue", size);
+        }
+        add("expanded", 10);
+        assert_eq!(counter("expanded"), 13);
+        let metrics = stop_collecting().unwrap();
+
+        assert_eq!(metrics.get("expanded"), Some(Metric::Counter(13)));
+        assert_eq!(metrics.get("queue"), Some(Metric::Gauge(2)));
+        assert_eq!(metrics.get("max_queue"), Some(Metric::Gauge(7)));
+        assert_eq!(metrics.get("ignored"), None);
+        assert_eq!(
+            metrics.to_json(),
+            "{\"expanded\":13,\"queue\":2,\"max_queue\":7}"
+        );
+
+        let mut out = Vec::new();
+        metrics.write(&mut out, 4).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "    expanded: 13\n    queue: 2\n    max_queue: 7\n"
+        );
+    }
+}
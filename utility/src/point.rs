@@ -0,0 +1,314 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::json::ToJson;
+
+// The signed integers points can be made of
+pub trait Coord:
+    Copy
+    + Ord
+    + fmt::Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(impl Coord for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+        })*
+    };
+}
+
+impl_coord!(i8, i16, i32, i64, isize);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coord> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn origin() -> Self {
+        Self::new(T::ZERO, T::ZERO)
+    }
+
+    // Number of steps between the points moving only up, down, left or right
+    pub fn manhattan(self, other: Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    // Number of steps between the points when diagonal steps are allowed too
+    pub fn chebyshev(self, other: Self) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    // Each coordinate as -1, 0 or 1, which turns a difference between points
+    // into a single step towards the second one
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    // The points up, right, down and left of this one, with y counting down
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        let (zero, one) = (T::ZERO, T::ONE);
+        [(zero, -one), (one, zero), (zero, one), (-one, zero)]
+            .into_iter()
+            .map(move |(dx, dy)| self + Self::new(dx, dy))
+    }
+
+    // Like neighbors4, and the diagonals too, clockwise from up
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        let (zero, one) = (T::ZERO, T::ONE);
+        [
+            (zero, -one),
+            (one, -one),
+            (one, zero),
+            (one, one),
+            (zero, one),
+            (-one, one),
+            (-one, zero),
+            (-one, -one),
+        ]
+        .into_iter()
+        .map(move |(dx, dy)| self + Self::new(dx, dy))
+    }
+}
+
+impl<T: Coord> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn origin() -> Self {
+        Self::new(T::ZERO, T::ZERO, T::ZERO)
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    // The points sharing a face with this one: -x, +x, -y, +y, -z, +z
+    pub fn neighbors6(self) -> impl Iterator<Item = Self> {
+        let (zero, one) = (T::ZERO, T::ONE);
+        [
+            (-one, zero, zero),
+            (one, zero, zero),
+            (zero, -one, zero),
+            (zero, one, zero),
+            (zero, zero, -one),
+            (zero, zero, one),
+        ]
+        .into_iter()
+        .map(move |(dx, dy, dz)| self + Self::new(dx, dy, dz))
+    }
+
+    // Every point in the 3x3x3 cube around this one, except itself
+    pub fn neighbors26(self) -> impl Iterator<Item = Self> {
+        let steps = [-T::ONE, T::ZERO, T::ONE];
+        steps
+            .into_iter()
+            .flat_map(move |dx| {
+                steps
+                    .into_iter()
+                    .flat_map(move |dy| steps.into_iter().map(move |dz| Self::new(dx, dy, dz)))
+            })
+            .filter(|&offset| offset != Self::origin())
+            .map(move |offset| self + offset)
+    }
+}
+
+macro_rules! impl_point_ops {
+    ($point:ident, $($field:ident),*) => {
+        impl<T: Coord> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),* }
+            }
+        }
+
+        impl<T: Coord> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),* }
+            }
+        }
+
+        // Scales every coordinate
+        impl<T: Coord> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, scale: T) -> Self {
+                Self { $($field: self.$field * scale),* }
+            }
+        }
+
+        impl<T: Coord> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),* }
+            }
+        }
+
+        impl<T: Coord> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Coord> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_point_ops!(Point2, x, y);
+impl_point_ops!(Point3, x, y, z);
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> From<Point3<T>> for (T, T, T) {
+    fn from(point: Point3<T>) -> Self {
+        (point.x, point.y, point.z)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+// Written as arrays, like the tuples they replace
+impl<T: ToJson> ToJson for Point2<T> {
+    fn to_json(&self) -> String {
+        format!("[{},{}]", self.x.to_json(), self.y.to_json())
+    }
+}
+
+impl<T: ToJson> ToJson for Point3<T> {
+    fn to_json(&self) -> String {
+        format!(
+            "[{},{},{}]",
+            self.x.to_json(),
+            self.y.to_json(),
+            self.z.to_json()
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point2::new(1, -2);
+        let b = Point2::from((4, 2));
+        assert_eq!(a + b, Point2::new(5, 0));
+        assert_eq!(b - a, Point2::new(3, 4));
+        assert_eq!(a * 3, Point2::new(3, -6));
+        assert_eq!(-a, Point2::new(-1, 2));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!((b - a).signum(), Point2::new(1, 1));
+        assert_eq!(<(i32, i32)>::from(a), (1, -2));
+
+        let mut c = Point3::new(1i64, 2, 3);
+        c += Point3::new(1, 1, 1);
+        c -= Point3::new(0, 0, 5);
+        assert_eq!(c, Point3::new(2, 3, -1));
+        assert_eq!(c.manhattan(Point3::origin()), 6);
+        assert_eq!(c.chebyshev(Point3::origin()), 3);
+        assert_eq!(c.to_string(), "(2, 3, -1)");
+        assert_eq!(c.to_json(), "[2,3,-1]");
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let point = Point2::new(0i64, 0);
+        assert_eq!(
+            point
+                .neighbors4()
+                .map(<(i64, i64)>::from)
+                .collect::<Vec<_>>(),
+            [(0, -1), (1, 0), (0, 1), (-1, 0)]
+        );
+        assert_eq!(point.neighbors8().count(), 8);
+        assert!(point.neighbors8().all(|other| point.chebyshev(other) == 1));
+
+        let point = Point3::new(5i32, 5, 5);
+        assert_eq!(point.neighbors6().count(), 6);
+        assert!(point.neighbors6().all(|other| point.manhattan(other) == 1));
+        let around = point.neighbors26().collect::<Vec<_>>();
+        assert_eq!(around.len(), 26);
+        assert!(!around.contains(&point));
+        assert!(around.iter().all(|&other| point.chebyshev(other) == 1));
+    }
+}
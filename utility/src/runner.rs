@@ -0,0 +1,747 @@
+use std::any::Any;
+use std::borrow::Borrow;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+use std::time::{Duration, Instant};
+
+use crate::asciicast::{self, CastSettings};
+use crate::catalog::Info;
+use crate::checkpoint::{self, CheckpointSettings, Checkpointer};
+use crate::dot::{self, DotSettings};
+use crate::image::{self, ImageFormat, ImageSettings};
+use crate::json::{Object, ToJson};
+use crate::metrics::{self, Metrics};
+use crate::read_file_lines;
+use crate::scaling::Scaling;
+use crate::span::{self, Span};
+use crate::trace::{self, Tracer};
+use crate::visualizer::{self, Simulation};
+
+pub type Visualize = fn(&[String]) -> Box<dyn Simulation>;
+
+// A day's parse step, and the two parts that both borrow the model it builds.
+// Work both parts need can go in the model, or in a OnceCell in the model so
+// whichever part runs first computes it. Parts can borrow the model as
+// something simpler, such as a Vec as a slice.
+pub struct Solution<M, B: ?Sized = M> {
+    pub parse: fn(&[String]) -> M,
+    pub part1: fn(&B) -> String,
+    pub part2: fn(&B) -> String,
+}
+
+// Runs a Solution without knowing its model type
+pub trait Solve: Sync {
+    fn parse(&self, file_lines: &[String]) -> Box<dyn Any>;
+    fn part1(&self, model: &dyn Any) -> String;
+    fn part2(&self, model: &dyn Any) -> String;
+}
+
+impl<M: Borrow<B> + 'static, B: ?Sized> Solution<M, B> {
+    fn model(model: &dyn Any) -> &B {
+        model
+            .downcast_ref::<M>()
+            .expect("Model was parsed by a different solution")
+            .borrow()
+    }
+}
+
+impl<M: Borrow<B> + 'static, B: ?Sized> Solve for Solution<M, B> {
+    fn parse(&self, file_lines: &[String]) -> Box<dyn Any> {
+        Box::new((self.parse)(file_lines))
+    }
+
+    fn part1(&self, model: &dyn Any) -> String {
+        (self.part1)(Self::model(model))
+    }
+
+    fn part2(&self, model: &dyn Any) -> String {
+        (self.part2)(Self::model(model))
+    }
+}
+
+// A day's solution, as seen by the runner
+pub struct Day {
+    pub name: &'static str,
+    pub input: &'static str,
+    // Title, techniques and examples, shown by aoc list
+    pub info: Info,
+    pub solution: &'static dyn Solve,
+    // Whether a line could be part of this day's input, used to identify files
+    pub is_input_line: fn(&str) -> bool,
+    // Builds a simulation to step through with --visualize
+    pub visualize: Option<Visualize>,
+    // How the parts should grow on generated inputs, checked by aoc scale
+    pub scaling: Option<Scaling>,
+}
+
+impl Day {
+    // Reads and parses the input, then runs the part. Parts run this way
+    // don't share anything with each other.
+    pub fn solve_part(&self, part_name: &str, file_lines: &[String]) -> String {
+        let model = self.solution.parse(file_lines);
+        match part_name {
+            "part1" => self.solution.part1(model.as_ref()),
+            "part2" => self.solution.part2(model.as_ref()),
+            _ => panic!("Unknown part: {}", part_name),
+        }
+    }
+}
+
+// A day named "test" that accepts any input line, for tests that only care
+// about the solution
+#[cfg(test)]
+pub(crate) const fn test_day(solution: &'static dyn Solve) -> Day {
+    Day {
+        name: "test",
+        input: "",
+        info: Info::new("Test"),
+        solution,
+        is_input_line: |_| true,
+        visualize: None,
+        scaling: None,
+    }
+}
+
+// Parses any input, for use with test_day
+#[cfg(test)]
+pub(crate) fn count_lines(file_lines: &[String]) -> usize {
+    file_lines.len()
+}
+
+#[cfg(test)]
+pub(crate) fn lines_part(num_lines: &usize) -> String {
+    num_lines.to_string()
+}
+
+// A panic caught while running a step, with where it happened
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    pub location: Option<String>,
+}
+
+impl fmt::Display for Panic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{} (at {})", self.message, location),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    // The input was parsed, which gives no answer of its own
+    Parsed,
+    Answer(String),
+    Panicked(Panic),
+    // Not run because an earlier step it depends on panicked
+    Skipped,
+}
+
+#[derive(Debug, Clone)]
+pub struct StepReport {
+    pub name: &'static str,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+    // Timing spans entered while the step ran
+    pub spans: Vec<Span>,
+    // Counters and gauges recorded while the step ran
+    pub metrics: Metrics,
+}
+
+impl ToJson for StepReport {
+    fn to_json(&self) -> String {
+        let (outcome, answer, panic) = match &self.outcome {
+            Outcome::Parsed => ("parsed", None, None),
+            Outcome::Answer(answer) => ("answer", Some(answer.clone()), None),
+            Outcome::Panicked(caught) => ("panicked", None, Some(caught.to_string())),
+            Outcome::Skipped => ("skipped", None, None),
+        };
+        Object::new()
+            .field("name", self.name)
+            .field("outcome", outcome)
+            .field("answer", answer)
+            .field("panic", panic)
+            .field("elapsed_us", self.elapsed.as_micros() as u64)
+            .field("spans", &self.spans)
+            .field("metrics", &self.metrics)
+            .to_json()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DayReport {
+    pub name: &'static str,
+    pub steps: Vec<StepReport>,
+}
+
+impl ToJson for DayReport {
+    fn to_json(&self) -> String {
+        Object::new()
+            .field("day", self.name)
+            .field("steps", &self.steps)
+            .to_json()
+    }
+}
+
+impl DayReport {
+    pub fn failures(&self) -> impl Iterator<Item = &StepReport> {
+        self.steps
+            .iter()
+            .filter(|step| matches!(step.outcome, Outcome::Panicked(_)))
+    }
+
+    pub fn succeeded(&self) -> bool {
+        self.failures().next().is_none()
+    }
+}
+
+thread_local! {
+    static CAPTURING_PANICS: Cell<bool> = const { Cell::new(false) };
+    static LAST_PANIC: RefCell<Option<Panic>> = const { RefCell::new(None) };
+}
+
+static INSTALL_PANIC_HOOK: Once = Once::new();
+
+fn payload_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+// Wraps the default panic hook so panics inside catch_panic are recorded
+// instead of printed. Panics anywhere else still go to the default hook.
+fn install_panic_hook() {
+    INSTALL_PANIC_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CAPTURING_PANICS.with(|capturing| capturing.get()) {
+                default_hook(info);
+                return;
+            }
+            let caught = Panic {
+                message: payload_message(info.payload()),
+                location: info.location().map(|location| location.to_string()),
+            };
+            LAST_PANIC.with(|last_panic| *last_panic.borrow_mut() = Some(caught));
+        }));
+    });
+}
+
+// Runs f, turning a panic into an Err with the panic message and location
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, Panic> {
+    install_panic_hook();
+    let was_capturing = CAPTURING_PANICS.with(|capturing| capturing.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CAPTURING_PANICS.with(|capturing| capturing.set(was_capturing));
+
+    result.map_err(|payload| {
+        LAST_PANIC
+            .with(|last_panic| last_panic.borrow_mut().take())
+            .unwrap_or_else(|| Panic {
+                message: payload_message(&*payload),
+                location: None,
+            })
+    })
+}
+
+// How long a step took, and what it recorded along the way
+struct Measurement {
+    elapsed: Duration,
+    spans: Vec<Span>,
+    metrics: Metrics,
+}
+
+impl Measurement {
+    fn write(&self, out: &mut dyn Write, name: &str) {
+        writeln!(out, "{} time: {}us", name, self.elapsed.as_micros()).unwrap();
+        span::write_tree(out, &self.spans, 4).unwrap();
+        if !self.metrics.is_empty() {
+            writeln!(out, "{} metrics:", name).unwrap();
+            self.metrics.write(out, 4).unwrap();
+        }
+        writeln!(out).unwrap();
+    }
+
+    fn into_report(self, name: &'static str, outcome: Outcome) -> StepReport {
+        StepReport {
+            name,
+            outcome,
+            elapsed: self.elapsed,
+            spans: self.spans,
+            metrics: self.metrics,
+        }
+    }
+}
+
+// Runs f, collecting the spans it enters and the metrics it records
+fn timed<T>(f: impl FnOnce() -> T) -> (Result<T, Panic>, Measurement) {
+    span::start_collecting();
+    metrics::start_collecting();
+    let start_time = Instant::now();
+    let result = catch_panic(f);
+    let elapsed = start_time.elapsed();
+    let measurement = Measurement {
+        elapsed,
+        spans: span::stop_collecting().unwrap_or_default(),
+        metrics: metrics::stop_collecting().unwrap_or_default(),
+    };
+    (result, measurement)
+}
+
+fn run_part(
+    out: &mut dyn Write,
+    name: &'static str,
+    model: Option<&dyn Any>,
+    part: impl FnOnce(&dyn Any) -> String,
+) -> StepReport {
+    writeln!(out, "*********** {} ***********", name.to_uppercase()).unwrap();
+    let Some(model) = model else {
+        writeln!(out, "{} skipped\n", name).unwrap();
+        return StepReport {
+            name,
+            outcome: Outcome::Skipped,
+            elapsed: Duration::ZERO,
+            spans: Vec::new(),
+            metrics: Metrics::new(),
+        };
+    };
+
+    let (result, measurement) = timed(|| part(model));
+    let outcome = match result {
+        Ok(answer) => {
+            writeln!(out, "{} answer: {}", name, answer).unwrap();
+            Outcome::Answer(answer)
+        }
+        Err(caught) => {
+            writeln!(out, "{} panicked: {}", name, caught).unwrap();
+            Outcome::Panicked(caught)
+        }
+    };
+    measurement.write(out, name);
+    measurement.into_report(name, outcome)
+}
+
+// Reads and parses the input, then runs both parts on the model, writing
+// progress to out as it goes. A panic in one step is recorded in the report
+// rather than unwinding further.
+pub fn run_day(day: &Day, input: &str, out: &mut dyn Write) -> DayReport {
+    // Parsing
+    writeln!(out, "Parsing...").unwrap();
+    trace::set_part("parse");
+    let (parsed, measurement) = timed(|| {
+        let file_lines = read_file_lines(input);
+        day.solution.parse(&file_lines)
+    });
+    let parse_outcome = match &parsed {
+        Ok(_) => Outcome::Parsed,
+        Err(caught) => {
+            writeln!(out, "Parsing panicked: {}", caught).unwrap();
+            Outcome::Panicked(caught.clone())
+        }
+    };
+    measurement.write(out, "Parsing");
+    let model = parsed.ok();
+
+    let mut steps = vec![measurement.into_report("Parsing", parse_outcome)];
+    trace::set_part("part1");
+    checkpoint::set_part("part1");
+    steps.push(run_part(out, "Part 1", model.as_deref(), |model| {
+        day.solution.part1(model)
+    }));
+    trace::set_part("part2");
+    checkpoint::set_part("part2");
+    steps.push(run_part(out, "Part 2", model.as_deref(), |model| {
+        day.solution.part2(model)
+    }));
+
+    DayReport {
+        name: day.name,
+        steps,
+    }
+}
+
+// Writes a one line summary for every failed step across the given reports.
+// Returns the number of failures.
+pub fn write_failure_summary(out: &mut dyn Write, reports: &[DayReport]) -> usize {
+    let failures = reports
+        .iter()
+        .flat_map(|report| report.failures().map(move |step| (report.name, step)))
+        .collect::<Vec<_>>();
+    if failures.is_empty() {
+        return 0;
+    }
+
+    writeln!(out, "{} failed step(s):", failures.len()).unwrap();
+    for (day_name, step) in failures.iter() {
+        if let Outcome::Panicked(caught) = &step.outcome {
+            writeln!(out, "  {} {}: {}", day_name, step.name, caught).unwrap();
+        }
+    }
+    failures.len()
+}
+
+// Writes the reports as a JSON array, one object per day
+pub fn write_json_report(path: &str, reports: &[DayReport]) -> io::Result<()> {
+    let mut file = io::BufWriter::new(std::fs::File::create(path)?);
+    writeln!(file, "{}", reports.to_json())?;
+    file.flush()
+}
+
+const USAGE: &str = "Options:
+    --cast <FILE>         Record the simulation frames to an asciicast v2 file
+    --cast-fps <N>        Frames per second when playing back the recording
+    --cast-every <N>      Only record one out of every N frames
+    --cast-rows <N>       Crop recorded frames to at most N rows
+    --image <PREFIX>      Write grid images to files named <PREFIX>-<name>.<ext>
+    --image-format <FMT>  Image format, ppm (default) or png
+    --image-scale <N>     Size in pixels of each grid cell
+    --image-every <N>     Only write one out of every N animation frames
+    --dot <FILE>          Write the day's graph to a Graphviz DOT file
+    --dot-highlight       Highlight the solution in the DOT graph
+    --trace <FILE>        Write simulation events to a JSON Lines file
+    --checkpoint <FILE>   Periodically save long searches' progress to a file
+    --save-every <N>      Seconds between checkpoint saves (default 60)
+    --resume              Continue the searches saved in the --checkpoint file
+    --json <FILE>         Write answers, timings, spans and metrics to a JSON file
+    --visualize           Step through the day's simulation interactively";
+
+// Command line options accepted by a single day's binary
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Options {
+    pub cast: Option<String>,
+    pub cast_settings: CastSettings,
+    pub image: Option<ImageSettings>,
+    pub dot: Option<DotSettings>,
+    pub trace: Option<String>,
+    pub checkpoint: Option<CheckpointSettings>,
+    pub json: Option<String>,
+    pub visualize: bool,
+}
+
+fn parse_value<T: std::str::FromStr>(flag: &str, value: Option<&String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("Missing value for {}", flag))?;
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value for {}: {}", flag, value))
+}
+
+// Frame timestamps are divided by the rate, so it has to be a positive number
+fn parse_fps(flag: &str, value: Option<&String>) -> Result<f64, String> {
+    let fps: f64 = parse_value(flag, value)?;
+    if fps.is_finite() && fps > 0.0 {
+        Ok(fps)
+    } else {
+        Err(format!("Invalid value for {}: {}", flag, value.unwrap()))
+    }
+}
+
+impl Options {
+    fn image_settings(&mut self) -> &mut ImageSettings {
+        self.image.get_or_insert_with(ImageSettings::default)
+    }
+
+    fn dot_settings(&mut self) -> &mut DotSettings {
+        self.dot.get_or_insert_with(DotSettings::default)
+    }
+
+    fn checkpoint_settings(&mut self) -> &mut CheckpointSettings {
+        self.checkpoint
+            .get_or_insert_with(CheckpointSettings::default)
+    }
+
+    pub fn parse(args: &[String]) -> Result<Options, String> {
+        let mut options = Options::default();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--cast" => options.cast = Some(parse_value(arg, args.next())?),
+                "--cast-fps" => options.cast_settings.fps = parse_fps(arg, args.next())?,
+                "--cast-every" => options.cast_settings.every = parse_value(arg, args.next())?,
+                "--cast-rows" => {
+                    options.cast_settings.max_rows = Some(parse_value(arg, args.next())?)
+                }
+                "--image" => options.image_settings().prefix = parse_value(arg, args.next())?,
+                "--image-format" => {
+                    options.image_settings().format = parse_value(arg, args.next())?
+                }
+                "--image-scale" => options.image_settings().scale = parse_value(arg, args.next())?,
+                "--image-every" => options.image_settings().every = parse_value(arg, args.next())?,
+                "--dot" => options.dot_settings().path = parse_value(arg, args.next())?,
+                "--dot-highlight" => options.dot_settings().highlight = true,
+                "--trace" => options.trace = Some(parse_value(arg, args.next())?),
+                "--checkpoint" => {
+                    options.checkpoint_settings().path = parse_value(arg, args.next())?
+                }
+                "--save-every" => {
+                    options.checkpoint_settings().every =
+                        Duration::from_secs(parse_value(arg, args.next())?)
+                }
+                "--resume" => options.checkpoint_settings().resume = true,
+                "--json" => options.json = Some(parse_value(arg, args.next())?),
+                "--visualize" => options.visualize = true,
+                _ => return Err(format!("Unknown argument: {}", arg)),
+            }
+        }
+
+        if options
+            .image
+            .as_ref()
+            .is_some_and(|settings| settings.format == ImageFormat::Png)
+            && !cfg!(feature = "png")
+        {
+            return Err("PNG output requires the utility crate's `png` feature".to_string());
+        }
+        if options
+            .dot
+            .as_ref()
+            .is_some_and(|settings| settings.path.is_empty())
+        {
+            return Err("--dot-highlight requires --dot <FILE>".to_string());
+        }
+        if options
+            .checkpoint
+            .as_ref()
+            .is_some_and(|settings| settings.path.is_empty())
+        {
+            return Err("--resume and --save-every require --checkpoint <FILE>".to_string());
+        }
+        Ok(options)
+    }
+}
+
+// Entry point for a single day's binary
+pub fn main(day: &Day) {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let options = Options::parse(&args).unwrap_or_else(|err| {
+        eprintln!("{}\n\n{}", err, USAGE);
+        std::process::exit(2);
+    });
+
+    if options.visualize {
+        let Some(visualize) = day.visualize else {
+            eprintln!("{} has no simulation to visualize", day.name);
+            std::process::exit(2);
+        };
+        let file_lines = read_file_lines(day.input);
+        visualizer::run(visualize(&file_lines).as_mut());
+        return;
+    }
+
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+
+    if options.cast.is_some() {
+        asciicast::start_recording(options.cast_settings);
+    }
+    if let Some(settings) = &options.image {
+        image::start_export(settings.clone());
+    }
+    if let Some(settings) = &options.dot {
+        dot::start_export(settings.clone());
+    }
+    if let Some(path) = &options.trace {
+        trace::start_trace(Tracer::create(path).expect("Unable to create trace file"));
+    }
+
+    if let Some(settings) = &options.checkpoint {
+        let run = format!("{} {}", day.name, day.input);
+        let checkpointer = Checkpointer::new(settings.clone(), &run).unwrap_or_else(|err| {
+            eprintln!("Unable to resume from {}: {}", settings.path, err);
+            std::process::exit(2);
+        });
+        checkpoint::start_checkpointing(checkpointer);
+    }
+
+    let report = run_day(day, day.input, &mut stdout);
+
+    if let (Some(path), Some(recorder)) = (&options.cast, asciicast::stop_recording()) {
+        recorder.save(path).expect("Unable to write asciicast file");
+        writeln!(
+            stdout,
+            "Recorded {} frames to {}",
+            recorder.frames().len(),
+            path
+        )
+        .unwrap();
+    }
+    if let Some(exporter) = image::stop_export() {
+        writeln!(stdout, "Wrote {} image(s)", exporter.files_written().len()).unwrap();
+    }
+    if let (Some(settings), Some(exporter)) = (&options.dot, dot::stop_export()) {
+        exporter.save().expect("Unable to write DOT file");
+        writeln!(
+            stdout,
+            "Wrote {} graph(s) to {}",
+            exporter.graphs().len(),
+            settings.path
+        )
+        .unwrap();
+    }
+    if let (Some(path), Some(tracer)) = (&options.trace, trace::stop_trace()) {
+        writeln!(
+            stdout,
+            "Wrote {} trace events to {}",
+            tracer.events_written(),
+            path
+        )
+        .unwrap();
+    }
+
+    if let (Some(settings), Some(checkpointer)) =
+        (&options.checkpoint, checkpoint::stop_checkpointing())
+    {
+        writeln!(
+            stdout,
+            "Saved {} checkpoint(s) to {}",
+            checkpointer.saves(),
+            settings.path
+        )
+        .unwrap();
+    }
+
+    let reports = [report];
+    if let Some(path) = &options.json {
+        write_json_report(path, &reports).expect("Unable to write JSON report");
+        writeln!(stdout, "Wrote report to {}", path).unwrap();
+    }
+
+    if write_failure_summary(&mut stdout, &reports) > 0 {
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::metrics::Metric;
+
+    fn counting_part(num_lines: &usize) -> String {
+        metrics::add("lines", *num_lines as u64);
+        metrics::gauge_max("max_line", 3);
+        num_lines.to_string()
+    }
+
+    fn panicking_part(_num_lines: &usize) -> String {
+        panic!("No solution for {}", "this part");
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        assert_eq!(catch_panic(|| 5), Ok(5));
+
+        let caught = catch_panic(|| -> i32 { panic!("Bad input") }).unwrap_err();
+        assert_eq!(caught.message, "Bad input");
+        assert!(caught.location.unwrap().contains("runner.rs"));
+    }
+
+    #[test]
+    fn test_run_day_isolates_parts() {
+        let day = test_day(&Solution {
+            parse: count_lines,
+            part1: panicking_part,
+            part2: lines_part,
+        });
+        let mut out = Vec::new();
+        let report = run_day(&day, "Cargo.toml", &mut out);
+        assert_eq!(report.failures().count(), 1);
+        assert_eq!(report.steps[0].outcome, Outcome::Parsed);
+        assert!(matches!(report.steps[1].outcome, Outcome::Panicked(_)));
+        assert!(matches!(report.steps[2].outcome, Outcome::Answer(_)));
+
+        let report = run_day(&day, "no-such-input.txt", &mut out);
+        assert!(matches!(report.steps[0].outcome, Outcome::Panicked(_)));
+        assert_eq!(report.steps[1].outcome, Outcome::Skipped);
+        assert_eq!(report.steps[2].outcome, Outcome::Skipped);
+    }
+
+    #[test]
+    fn test_run_day_reports_metrics() {
+        let day = test_day(&Solution {
+            parse: count_lines,
+            part1: counting_part,
+            part2: lines_part,
+        });
+        let mut out = Vec::new();
+        let report = run_day(&day, "Cargo.toml", &mut out);
+        let lines = report.steps[1].metrics.get("lines");
+        assert!(matches!(lines, Some(Metric::Counter(n)) if n > 0));
+        assert!(report.steps[2].metrics.is_empty());
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("Part 1 metrics:\n    lines: "));
+        assert!(!out.contains("Part 2 metrics:"));
+
+        let json = report.to_json();
+        assert!(json.starts_with(
+            "{\"day\":\"test\",\"steps\":[{\"name\":\"Parsing\",\"outcome\":\"parsed\",\"answer\":null,"
+        ));
+        assert!(json.contains("\"metrics\":{\"lines\":"));
+        assert!(json.contains(",\"max_line\":3}"));
+    }
+
+    #[test]
+    fn test_options() {
+        let args = ["--cast", "out.cast", "--cast-every", "5"].map(String::from);
+        let options = Options::parse(&args).unwrap();
+        assert_eq!(options.cast.as_deref(), Some("out.cast"));
+        assert_eq!(options.cast_settings.every, 5);
+
+        assert_eq!(options.image, None);
+        assert_eq!(options.trace, None);
+
+        let args = ["--image", "out/day14", "--image-scale", "4"].map(String::from);
+        let image = Options::parse(&args).unwrap().image.unwrap();
+        assert_eq!(image.prefix, "out/day14");
+        assert_eq!(image.scale, 4);
+        assert_eq!(image.format, ImageFormat::Ppm);
+
+        assert!(
+            Options::parse(&["--visualize".to_string()])
+                .unwrap()
+                .visualize
+        );
+        let args = ["--trace", "day9.jsonl"].map(String::from);
+        let options = Options::parse(&args).unwrap();
+        assert_eq!(options.trace.as_deref(), Some("day9.jsonl"));
+
+        let args = ["--json", "day16.json"].map(String::from);
+        let options = Options::parse(&args).unwrap();
+        assert_eq!(options.json.as_deref(), Some("day16.json"));
+        assert!(Options::parse(&["--json".to_string()]).is_err());
+
+        let args = ["--resume", "--checkpoint", "day19.ckpt"].map(String::from);
+        let checkpoint = Options::parse(&args).unwrap().checkpoint.unwrap();
+        assert_eq!(checkpoint.path, "day19.ckpt");
+        assert_eq!(checkpoint.every, Duration::from_secs(60));
+        assert!(checkpoint.resume);
+        assert!(Options::parse(&["--resume".to_string()]).is_err());
+
+        let args = ["--dot-highlight", "--dot", "day16.dot"].map(String::from);
+        let dot = Options::parse(&args).unwrap().dot.unwrap();
+        assert_eq!(dot.path, "day16.dot");
+        assert!(dot.highlight);
+        assert!(Options::parse(&["--dot-highlight".to_string()]).is_err());
+
+        assert!(Options::parse(&["--cast".to_string()]).is_err());
+        assert!(Options::parse(&["--bogus".to_string()]).is_err());
+
+        let args = ["--cast-fps", "2.5"].map(String::from);
+        assert_eq!(Options::parse(&args).unwrap().cast_settings.fps, 2.5);
+        for fps in ["0", "-1", "inf", "NaN"] {
+            let args = ["--cast-fps", fps].map(String::from);
+            assert_eq!(
+                Options::parse(&args).unwrap_err(),
+                format!("Invalid value for --cast-fps: {}", fps)
+            );
+        }
+    }
+}
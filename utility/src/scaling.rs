@@ -0,0 +1,225 @@
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use crate::runner::Day;
+
+// How much faster than its declared class a part's running time may grow
+// before it's reported, to allow for noisy timings
+pub const TOLERANCE: f64 = 0.35;
+
+// Each size is timed this many times and the fastest run is kept
+const RUNS: usize = 3;
+
+// Upper bounds on how a part's running time grows with its input size
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Complexity {
+    Linear,
+    Linearithmic,
+    Quadratic,
+    Cubic,
+}
+
+impl Complexity {
+    // The exponent a part in this class grows by, as fitted over the sizes
+    // the suite runs. n log n grows a little faster than n at those sizes.
+    pub fn exponent(self) -> f64 {
+        match self {
+            Complexity::Linear => 1.0,
+            Complexity::Linearithmic => 1.2,
+            Complexity::Quadratic => 2.0,
+            Complexity::Cubic => 3.0,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Complexity::Linear => "O(n)",
+            Complexity::Linearithmic => "O(n log n)",
+            Complexity::Quadratic => "O(n^2)",
+            Complexity::Cubic => "O(n^3)",
+        }
+    }
+}
+
+// How a day's parts should grow on generated inputs
+#[derive(Debug, Clone, Copy)]
+pub struct Scaling {
+    // Builds an input with roughly n of the day's items in it
+    pub generate: fn(usize) -> Vec<String>,
+    // The values of n to time the parts at, smallest first
+    pub sizes: &'static [usize],
+    // The class each part is declared to be in, if it's checked
+    pub part1: Option<Complexity>,
+    pub part2: Option<Complexity>,
+}
+
+// Small deterministic generator, so generated inputs are the same every run
+pub struct Random(u64);
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        // xorshift64*
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    // A value from min to max, inclusive
+    pub fn range(&mut self, min: i64, max: i64) -> i64 {
+        min + (self.next_u64() % (max - min + 1) as u64) as i64
+    }
+}
+
+// The slope of a least squares fit of log(time) against log(size), which is
+// the exponent k when the time grows like n^k
+pub fn fit_exponent(samples: &[(usize, Duration)]) -> f64 {
+    let points = samples
+        .iter()
+        .map(|(size, elapsed)| {
+            let seconds = elapsed.as_secs_f64().max(1e-9);
+            ((*size as f64).ln(), seconds.ln())
+        })
+        .collect::<Vec<_>>();
+    let count = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / count;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / count;
+    let covariance = points
+        .iter()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum::<f64>();
+    let variance = points
+        .iter()
+        .map(|(x, _)| (x - mean_x) * (x - mean_x))
+        .sum::<f64>();
+    covariance / variance
+}
+
+// One part's timings at each size and how fast they grew
+#[derive(Debug, Clone)]
+pub struct PartScaling {
+    pub name: &'static str,
+    pub complexity: Complexity,
+    pub samples: Vec<(usize, Duration)>,
+    pub exponent: f64,
+}
+
+impl PartScaling {
+    pub fn new(
+        name: &'static str,
+        complexity: Complexity,
+        samples: Vec<(usize, Duration)>,
+    ) -> Self {
+        let exponent = fit_exponent(&samples);
+        Self {
+            name,
+            complexity,
+            samples,
+            exponent,
+        }
+    }
+
+    pub fn passed(&self) -> bool {
+        self.exponent <= self.complexity.exponent() + TOLERANCE
+    }
+}
+
+fn fastest_run(run: impl Fn() -> String) -> Duration {
+    (0..RUNS)
+        .map(|_| {
+            let start_time = Instant::now();
+            std::hint::black_box(run());
+            start_time.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+// Times each checked part of the day on inputs of every size. Parsing isn't
+// timed, so each part's own growth is measured.
+pub fn measure(day: &Day, scaling: &Scaling) -> Vec<PartScaling> {
+    let mut samples = [Vec::new(), Vec::new()];
+    for &size in scaling.sizes.iter() {
+        let file_lines = (scaling.generate)(size);
+        let model = day.solution.parse(&file_lines);
+        if scaling.part1.is_some() {
+            let elapsed = fastest_run(|| day.solution.part1(model.as_ref()));
+            samples[0].push((size, elapsed));
+        }
+        if scaling.part2.is_some() {
+            let elapsed = fastest_run(|| day.solution.part2(model.as_ref()));
+            samples[1].push((size, elapsed));
+        }
+    }
+
+    let [part1_samples, part2_samples] = samples;
+    let mut parts = Vec::new();
+    if let Some(complexity) = scaling.part1 {
+        parts.push(PartScaling::new("Part 1", complexity, part1_samples));
+    }
+    if let Some(complexity) = scaling.part2 {
+        parts.push(PartScaling::new("Part 2", complexity, part2_samples));
+    }
+    parts
+}
+
+pub fn write_part_scaling(out: &mut dyn Write, part: &PartScaling) -> io::Result<()> {
+    writeln!(out, "{} (declared {}):", part.name, part.complexity.name())?;
+    for (size, elapsed) in part.samples.iter() {
+        writeln!(out, "    n={}: {}us", size, elapsed.as_micros())?;
+    }
+    writeln!(
+        out,
+        "    grows like n^{:.2}{}",
+        part.exponent,
+        if part.passed() {
+            ""
+        } else {
+            ", EXCEEDS DECLARED CLASS"
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn samples(time: impl Fn(f64) -> f64) -> Vec<(usize, Duration)> {
+        [100, 200, 400, 800]
+            .iter()
+            .map(|&size| (size, Duration::from_secs_f64(time(size as f64))))
+            .collect()
+    }
+
+    #[test]
+    fn test_fit_exponent() {
+        let linear = fit_exponent(&samples(|n| n * 1e-6));
+        assert!((linear - 1.0).abs() < 1e-6);
+        let quadratic = fit_exponent(&samples(|n| n * n * 1e-6));
+        assert!((quadratic - 2.0).abs() < 1e-6);
+        let linearithmic = fit_exponent(&samples(|n| n * n.ln() * 1e-6));
+        assert!(linearithmic > 1.0 && linearithmic < Complexity::Linearithmic.exponent());
+    }
+
+    #[test]
+    fn test_passed() {
+        let quadratic = samples(|n| n * n * 1e-6);
+        assert!(PartScaling::new("Part 1", Complexity::Quadratic, quadratic.clone()).passed());
+        assert!(!PartScaling::new("Part 1", Complexity::Linearithmic, quadratic).passed());
+    }
+
+    #[test]
+    fn test_random() {
+        let mut random = Random::new(7);
+        let values = (0..100).map(|_| random.range(-3, 3)).collect::<Vec<_>>();
+        assert!(values.iter().all(|value| (-3..=3).contains(value)));
+        assert!(values.contains(&-3) && values.contains(&3));
+
+        let mut again = Random::new(7);
+        assert_eq!(again.range(-3, 3), values[0]);
+    }
+}
@@ -0,0 +1,205 @@
+use std::cell::RefCell;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use crate::json::{Object, ToJson};
+
+// A named stretch of a part's run time. Spans entered again under the same
+// parent, such as in a loop, are merged into one with a call count.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub name: &'static str,
+    pub elapsed: Duration,
+    pub calls: usize,
+    pub children: Vec<Span>,
+}
+
+impl Span {
+    fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            elapsed: Duration::ZERO,
+            calls: 0,
+            children: Vec::new(),
+        }
+    }
+
+    fn child_index(&mut self, name: &'static str) -> usize {
+        match self.children.iter().position(|child| child.name == name) {
+            Some(index) => index,
+            None => {
+                self.children.push(Span::new(name));
+                self.children.len() - 1
+            }
+        }
+    }
+
+    fn descendant(&mut self, path: &[usize]) -> &mut Span {
+        path.iter()
+            .fold(self, |span, &index| &mut span.children[index])
+    }
+}
+
+impl ToJson for Span {
+    fn to_json(&self) -> String {
+        Object::new()
+            .field("name", self.name)
+            .field("elapsed_us", self.elapsed.as_micros() as u64)
+            .field("calls", self.calls)
+            .field("children", &self.children)
+            .to_json()
+    }
+}
+
+// Writes spans and their children as a tree, one span per line
+pub fn write_tree(out: &mut dyn Write, spans: &[Span], indent: usize) -> io::Result<()> {
+    for span in spans.iter() {
+        write!(
+            out,
+            "{:indent$}{}: {}us",
+            "",
+            span.name,
+            span.elapsed.as_micros(),
+            indent = indent
+        )?;
+        if span.calls > 1 {
+            write!(out, " ({} calls)", span.calls)?;
+        }
+        writeln!(out)?;
+        write_tree(out, &span.children, indent + 4)?;
+    }
+    Ok(())
+}
+
+// Collects the spans entered on this thread as children of root
+struct Collector {
+    root: Span,
+    // Path of child indices from root to each open span, with when it was entered
+    open: Vec<(Vec<usize>, Instant)>,
+}
+
+impl Collector {
+    fn enter(&mut self, name: &'static str) {
+        let mut path = self
+            .open
+            .last()
+            .map(|(path, _)| path.clone())
+            .unwrap_or_default();
+        let index = self.root.descendant(&path).child_index(name);
+        path.push(index);
+        self.open.push((path, Instant::now()));
+    }
+
+    fn exit(&mut self) {
+        if let Some((path, start_time)) = self.open.pop() {
+            let span = self.root.descendant(&path);
+            span.elapsed += start_time.elapsed();
+            span.calls += 1;
+        }
+    }
+}
+
+thread_local! {
+    static COLLECTOR: RefCell<Option<Collector>> = const { RefCell::new(None) };
+}
+
+pub fn start_collecting() {
+    COLLECTOR.with(|collector| {
+        *collector.borrow_mut() = Some(Collector {
+            root: Span::new(""),
+            open: Vec::new(),
+        })
+    });
+}
+
+// Returns the top level spans entered since collecting started
+pub fn stop_collecting() -> Option<Vec<Span>> {
+    COLLECTOR.with(|collector| {
+        collector
+            .borrow_mut()
+            .take()
+            .map(|collector| collector.root.children)
+    })
+}
+
+// Ends its span when dropped
+#[must_use = "the span ends as soon as the guard is dropped"]
+pub struct SpanGuard {
+    active: bool,
+}
+
+impl Drop for SpanGuard {
+    fn drop(&mut self) {
+        if self.active {
+            COLLECTOR.with(|collector| {
+                if let Some(collector) = collector.borrow_mut().as_mut() {
+                    collector.exit();
+                }
+            });
+        }
+    }
+}
+
+// Starts a span that lasts until the returned guard is dropped. Does nothing
+// unless the runner is collecting spans, so it's cheap to leave in.
+pub fn enter(name: &'static str) -> SpanGuard {
+    let active = COLLECTOR.with(|collector| match collector.borrow_mut().as_mut() {
+        Some(collector) => {
+            collector.enter(name);
+            true
+        }
+        None => false,
+    });
+    SpanGuard { active }
+}
+
+// Runs f inside a span
+pub fn time<T>(name: &'static str, f: impl FnOnce() -> T) -> T {
+    let _span = enter(name);
+    f()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn names(spans: &[Span]) -> Vec<(&'static str, usize)> {
+        spans
+            .iter()
+            .map(|child| (child.name, child.calls))
+            .collect()
+    }
+
+    #[test]
+    fn test_spans() {
+        stop_collecting();
+        let _ignored = enter("ignored");
+
+        start_collecting();
+        time("parse", || {
+            for _ in 0..3 {
+                time("line", || ());
+            }
+        });
+        {
+            let _search = enter("search");
+            time("step", || ());
+        }
+        time("parse", || ());
+        let spans = stop_collecting().unwrap();
+
+        assert_eq!(names(&spans), [("parse", 2), ("search", 1)]);
+        assert_eq!(names(&spans[0].children), [("line", 3)]);
+        assert_eq!(names(&spans[1].children), [("step", 1)]);
+        assert!(spans[0].elapsed >= spans[0].children[0].elapsed);
+
+        let mut out = Vec::new();
+        write_tree(&mut out, &spans, 0).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines = out.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("parse: ") && lines[0].ends_with("us (2 calls)"));
+        assert!(lines[1].starts_with("    line: ") && lines[1].ends_with("us (3 calls)"));
+        assert!(lines[3].starts_with("    step: ") && lines[3].ends_with("us"));
+    }
+}
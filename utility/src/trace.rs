@@ -0,0 +1,140 @@
+use std::cell::RefCell;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+
+use crate::json::{Object, ToJson};
+
+// Writes simulation events as JSON Lines. Every line is one object that starts
+// with these fields, followed by the fields of the event itself:
+//   "part":  the step being run: "parse" while the input is parsed, then
+//            "part1" or "part2"
+//   "event": the kind of event, documented by each day next to its DAY
+//   "seq":   the index of the event within the part, counting from 0
+pub struct Tracer {
+    out: Box<dyn Write>,
+    part: String,
+    seq: usize,
+    events_written: usize,
+}
+
+impl Tracer {
+    pub fn new(out: Box<dyn Write>) -> Self {
+        Self {
+            out,
+            part: String::new(),
+            seq: 0,
+            events_written: 0,
+        }
+    }
+
+    pub fn create(path: &str) -> io::Result<Self> {
+        Ok(Self::new(Box::new(BufWriter::new(File::create(path)?))))
+    }
+
+    // Events are numbered from 0 again in each step
+    pub fn set_part(&mut self, part: &str) {
+        self.part = part.to_string();
+        self.seq = 0;
+    }
+
+    pub fn event(&mut self, event: &str, fields: Object) -> io::Result<()> {
+        let line = Object::new()
+            .field("part", self.part.as_str())
+            .field("event", event)
+            .field("seq", self.seq)
+            .extend(fields);
+        writeln!(self.out, "{}", line.to_json())?;
+        self.seq += 1;
+        self.events_written += 1;
+        Ok(())
+    }
+
+    pub fn events_written(&self) -> usize {
+        self.events_written
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+}
+
+thread_local! {
+    static TRACER: RefCell<Option<Tracer>> = const { RefCell::new(None) };
+}
+
+pub fn start_trace(tracer: Tracer) {
+    TRACER.with(|current| *current.borrow_mut() = Some(tracer));
+}
+
+pub fn stop_trace() -> Option<Tracer> {
+    let mut tracer = TRACER.with(|current| current.borrow_mut().take())?;
+    tracer.flush().expect("Unable to write trace file");
+    Some(tracer)
+}
+
+pub fn set_part(part: &str) {
+    TRACER.with(|current| {
+        if let Some(tracer) = current.borrow_mut().as_mut() {
+            tracer.set_part(part);
+        }
+    });
+}
+
+// Simulations call this with the event's fields. It does nothing unless a
+// trace has been started, so it's cheap to leave in.
+pub fn event(event: &str, fields: impl FnOnce() -> Object) {
+    TRACER.with(|current| {
+        if let Some(tracer) = current.borrow_mut().as_mut() {
+            tracer
+                .event(event, fields())
+                .expect("Unable to write trace file");
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::rc::Rc;
+
+    // Lets the test read back what the tracer wrote
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_trace() {
+        stop_trace();
+        event("ignored", || {
+            panic!("Should not build events when not tracing")
+        });
+
+        let buffer = SharedBuffer::default();
+        start_trace(Tracer::new(Box::new(buffer.clone())));
+        set_part("part1");
+        event("move", || Object::new().field("pos", (1, 2)));
+        event("move", || Object::new().field("pos", (2, 2)));
+        set_part("part2");
+        event("done", Object::new);
+        assert_eq!(stop_trace().unwrap().events_written(), 3);
+
+        let written = String::from_utf8(buffer.0.borrow().clone()).unwrap();
+        assert_eq!(
+            written.lines().collect::<Vec<_>>(),
+            [
+                "{\"part\":\"part1\",\"event\":\"move\",\"seq\":0,\"pos\":[1,2]}",
+                "{\"part\":\"part1\",\"event\":\"move\",\"seq\":1,\"pos\":[2,2]}",
+                "{\"part\":\"part2\",\"event\":\"done\",\"seq\":0}",
+            ]
+        );
+    }
+}
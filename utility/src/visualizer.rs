@@ -0,0 +1,386 @@
+use std::io::{self, BufRead, Write};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+// A step-based simulation that can be driven by the visualizer
+pub trait Simulation {
+    // Advances by one step. Returns false once there is nothing left to do.
+    fn step(&mut self) -> bool;
+    fn render(&self) -> String;
+}
+
+const HELP: &str =
+    "[enter] step  p pause/run  g N run to step N  + - speed  h j k l [N] scroll  0 home  q quit";
+
+// Redraw at most this often while running, taking several steps per frame if needed
+const MAX_FRAMES_PER_SECOND: f64 = 30.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Step,
+    TogglePause,
+    RunTo(usize),
+    Faster,
+    Slower,
+    // Rows and columns to move the viewport by
+    Scroll(isize, isize),
+    Home,
+    Quit,
+}
+
+impl std::str::FromStr for Command {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        let Some(command) = parts.next() else {
+            return Ok(Command::Step);
+        };
+        let count = match parts.next() {
+            Some(count) => count
+                .parse::<usize>()
+                .map_err(|_| format!("Invalid number: {}", count))?,
+            None => 1,
+        };
+        let scroll_amount = count as isize;
+        match command {
+            "s" | "n" => Ok(Command::Step),
+            "p" => Ok(Command::TogglePause),
+            "g" => Ok(Command::RunTo(count)),
+            "+" => Ok(Command::Faster),
+            "-" => Ok(Command::Slower),
+            "h" => Ok(Command::Scroll(0, -scroll_amount)),
+            "j" => Ok(Command::Scroll(scroll_amount, 0)),
+            "k" => Ok(Command::Scroll(-scroll_amount, 0)),
+            "l" => Ok(Command::Scroll(0, scroll_amount)),
+            "0" => Ok(Command::Home),
+            "q" => Ok(Command::Quit),
+            _ => Err(format!("Unknown command: {}", command)),
+        }
+    }
+}
+
+// The part of a rendered frame that is visible on screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Viewport {
+    pub row: usize,
+    pub col: usize,
+    pub rows: usize,
+    pub cols: usize,
+}
+
+impl Viewport {
+    // Uses the LINES and COLUMNS environment variables when they are set,
+    // leaving room for the status lines
+    pub fn from_env() -> Self {
+        let size = |name: &str, default: usize| {
+            std::env::var(name)
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(default)
+        };
+        Self {
+            row: 0,
+            col: 0,
+            rows: size("LINES", 40).saturating_sub(3).max(1),
+            cols: size("COLUMNS", 120).max(1),
+        }
+    }
+
+    // Keeps the viewport inside the frame where possible
+    pub fn scroll(&mut self, rows: isize, cols: isize, frame: &str) {
+        let frame_rows = frame.lines().count();
+        let frame_cols = frame
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let max_row = frame_rows.saturating_sub(self.rows);
+        let max_col = frame_cols.saturating_sub(self.cols);
+        self.row = self.row.saturating_add_signed(rows).min(max_row);
+        self.col = self.col.saturating_add_signed(cols).min(max_col);
+    }
+
+    pub fn crop(&self, frame: &str) -> String {
+        frame
+            .lines()
+            .skip(self.row)
+            .take(self.rows)
+            .map(|line| {
+                line.chars()
+                    .skip(self.col)
+                    .take(self.cols)
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Visualizer {
+    pub steps: usize,
+    pub finished: bool,
+    pub paused: bool,
+    pub run_to: Option<usize>,
+    pub steps_per_second: f64,
+    pub viewport: Viewport,
+    pub message: String,
+}
+
+impl Visualizer {
+    pub fn new(viewport: Viewport) -> Self {
+        Self {
+            steps: 0,
+            finished: false,
+            paused: true,
+            run_to: None,
+            steps_per_second: 10.0,
+            viewport,
+            message: String::new(),
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        !self.finished && (!self.paused || self.run_to.is_some())
+    }
+
+    fn step(&mut self, sim: &mut dyn Simulation) {
+        if self.finished {
+            return;
+        }
+        if sim.step() {
+            self.steps += 1;
+        } else {
+            self.finished = true;
+            self.message = format!("Finished after {} steps", self.steps);
+        }
+        if self.run_to.is_some_and(|run_to| self.steps >= run_to) {
+            self.run_to = None;
+            self.paused = true;
+        }
+    }
+
+    // Returns false when the visualizer should exit
+    pub fn apply(&mut self, command: Command, sim: &mut dyn Simulation) -> bool {
+        self.message.clear();
+        match command {
+            Command::Step => {
+                self.paused = true;
+                self.run_to = None;
+                self.step(sim);
+            }
+            Command::TogglePause => self.paused = !self.paused,
+            Command::RunTo(step) => {
+                if step > self.steps {
+                    self.run_to = Some(step);
+                } else {
+                    self.message = format!("Already at step {}", self.steps);
+                }
+            }
+            Command::Faster => self.steps_per_second *= 2.0,
+            Command::Slower => self.steps_per_second = (self.steps_per_second / 2.0).max(0.5),
+            Command::Scroll(rows, cols) => self.viewport.scroll(rows, cols, &sim.render()),
+            Command::Home => {
+                self.viewport.row = 0;
+                self.viewport.col = 0;
+            }
+            Command::Quit => return false,
+        }
+        true
+    }
+
+    // Time to wait between frames while running, and the steps to take each frame
+    pub fn frame_timing(&self) -> (Duration, usize) {
+        let frames_per_second = self.steps_per_second.min(MAX_FRAMES_PER_SECOND);
+        let steps_per_frame = (self.steps_per_second / frames_per_second).round() as usize;
+        (
+            Duration::from_secs_f64(1.0 / frames_per_second),
+            steps_per_frame.max(1),
+        )
+    }
+
+    // Advances a running visualizer by one frame's worth of steps
+    pub fn tick(&mut self, sim: &mut dyn Simulation) {
+        let (_, steps_per_frame) = self.frame_timing();
+        for _ in 0..steps_per_frame {
+            if !self.is_running() {
+                break;
+            }
+            self.step(sim);
+        }
+    }
+
+    pub fn draw(&self, sim: &dyn Simulation, out: &mut dyn Write) -> io::Result<()> {
+        let state = if self.finished {
+            "finished".to_string()
+        } else if let Some(run_to) = self.run_to {
+            format!("running to step {}", run_to)
+        } else if self.paused {
+            "paused".to_string()
+        } else {
+            "running".to_string()
+        };
+        write!(out, "\x1b[H\x1b[2J")?;
+        writeln!(
+            out,
+            "{}\r",
+            self.viewport.crop(&sim.render()).replace('\n', "\r\n")
+        )?;
+        writeln!(
+            out,
+            "step {} | {} | {} steps/s | view {},{} | {}\r",
+            self.steps,
+            state,
+            self.steps_per_second,
+            self.viewport.row,
+            self.viewport.col,
+            self.message
+        )?;
+        write!(out, "{}\r\n> ", HELP)?;
+        out.flush()
+    }
+}
+
+// Runs an interactive session reading commands from stdin, one per line
+pub fn run(sim: &mut dyn Simulation) {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else { break };
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+    let mut visualizer = Visualizer::new(Viewport::from_env());
+    visualizer.draw(sim, &mut stdout).unwrap();
+    loop {
+        let line = if visualizer.is_running() {
+            let (frame_time, _) = visualizer.frame_timing();
+            receiver.recv_timeout(frame_time)
+        } else {
+            receiver.recv().map_err(|_| RecvTimeoutError::Disconnected)
+        };
+
+        match line {
+            Ok(line) => match line.parse::<Command>() {
+                Ok(command) => {
+                    if !visualizer.apply(command, sim) {
+                        break;
+                    }
+                }
+                Err(err) => visualizer.message = err,
+            },
+            Err(RecvTimeoutError::Timeout) => visualizer.tick(sim),
+            Err(RecvTimeoutError::Disconnected) => {
+                if !visualizer.is_running() {
+                    break;
+                }
+                visualizer.tick(sim);
+            }
+        }
+        visualizer.draw(sim, &mut stdout).unwrap();
+    }
+    writeln!(stdout).unwrap();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Counts up to a limit, rendering a wide and tall frame
+    struct Counter {
+        value: usize,
+        limit: usize,
+    }
+
+    impl Simulation for Counter {
+        fn step(&mut self) -> bool {
+            if self.value == self.limit {
+                return false;
+            }
+            self.value += 1;
+            true
+        }
+
+        fn render(&self) -> String {
+            (0..10)
+                .map(|row| format!("{}{}", row, "#".repeat(self.value)))
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+    }
+
+    fn viewport() -> Viewport {
+        Viewport {
+            row: 0,
+            col: 0,
+            rows: 3,
+            cols: 4,
+        }
+    }
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!("".parse::<Command>(), Ok(Command::Step));
+        assert_eq!("g 100".parse::<Command>(), Ok(Command::RunTo(100)));
+        assert_eq!("j 5".parse::<Command>(), Ok(Command::Scroll(5, 0)));
+        assert_eq!("h".parse::<Command>(), Ok(Command::Scroll(0, -1)));
+        assert!("g x".parse::<Command>().is_err());
+        assert!("x".parse::<Command>().is_err());
+    }
+
+    #[test]
+    fn test_run_to_and_finish() {
+        let mut sim = Counter { value: 0, limit: 5 };
+        let mut visualizer = Visualizer::new(viewport());
+        assert!(visualizer.apply(Command::Step, &mut sim));
+        assert_eq!(visualizer.steps, 1);
+
+        visualizer.apply(Command::RunTo(3), &mut sim);
+        while visualizer.is_running() {
+            visualizer.tick(&mut sim);
+        }
+        assert_eq!(visualizer.steps, 3);
+        assert!(visualizer.paused);
+
+        visualizer.apply(Command::TogglePause, &mut sim);
+        while visualizer.is_running() {
+            visualizer.tick(&mut sim);
+        }
+        assert!(visualizer.finished);
+        assert_eq!(sim.value, 5);
+        assert!(!visualizer.apply(Command::Quit, &mut sim));
+    }
+
+    #[test]
+    fn test_viewport() {
+        let sim = Counter { value: 5, limit: 5 };
+        let mut viewport = viewport();
+        assert_eq!(viewport.crop(&sim.render()), "0###\n1###\n2###");
+
+        viewport.scroll(2, 1, &sim.render());
+        assert_eq!(viewport.crop(&sim.render()), "####\n####\n####");
+        assert_eq!((viewport.row, viewport.col), (2, 1));
+
+        // Can't scroll past the edges of the frame
+        viewport.scroll(100, 100, &sim.render());
+        assert_eq!((viewport.row, viewport.col), (7, 2));
+        viewport.scroll(-100, -100, &sim.render());
+        assert_eq!((viewport.row, viewport.col), (0, 0));
+    }
+
+    #[test]
+    fn test_frame_timing() {
+        let mut visualizer = Visualizer::new(viewport());
+        visualizer.steps_per_second = 10.0;
+        assert_eq!(visualizer.frame_timing(), (Duration::from_millis(100), 1));
+        visualizer.steps_per_second = 300.0;
+        assert_eq!(visualizer.frame_timing().1, 10);
+    }
+}